@@ -0,0 +1,96 @@
+//! Generates a test case for every example input
+//!
+//! Each `examples/dayN/NAME.txt` with a matching `NAME.expected` becomes a `dayN_NAME` test in
+//! `tests/examples.rs`, and each `examples/YEAR/dayN/NAME.txt` a `yYEAR_dayN_NAME` test. The
+//! expected file has the same format as `answers/dayN.txt`: part one on the first line, part two
+//! on the second, and a blank line for a part that shouldn't be checked.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn test_name(day: &str, stem: &str) -> String {
+    let stem: String = stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}_{}", day, stem)
+}
+
+/// The year of examples directly under `examples/`, as with `aoc::DEFAULT_YEAR`
+const DEFAULT_YEAR: u64 = 2021;
+
+/// Every example in the `dayN` directories under `dir`, named with `prefix`
+fn day_examples(dir: &Path, year: u64, prefix: &str, found: &mut Vec<(u64, u64, String, PathBuf)>) {
+    let days = match fs::read_dir(dir) {
+        Ok(days) => days,
+        Err(_) => return,
+    };
+
+    for entry in days {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        let day = match name.strip_prefix("day").and_then(|n| n.parse().ok()) {
+            Some(day) => day,
+            None => continue,
+        };
+
+        for file in fs::read_dir(&path).unwrap() {
+            let file = file.unwrap().path();
+            if file.extension().is_some_and(|ext| ext == "txt")
+                && file.with_extension("expected").exists()
+            {
+                let stem = file.file_stem().unwrap().to_string_lossy();
+                let name = format!("{}{}", prefix, name);
+                found.push((year, day, test_name(&name, &stem), file));
+            }
+        }
+    }
+}
+
+fn examples(dir: &Path) -> Vec<(u64, u64, String, PathBuf)> {
+    let mut found = Vec::new();
+    day_examples(dir, DEFAULT_YEAR, "", &mut found);
+
+    // Other years have their own directory, like `examples/2022/day1`
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            if let Ok(year) = name.parse() {
+                day_examples(&path, year, &format!("y{}_", year), &mut found);
+            }
+        }
+    }
+    found.sort();
+    found
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let examples_dir = manifest_dir.join("examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+    let mut out = String::new();
+    for (year, day, name, path) in examples(&examples_dir) {
+        writeln!(
+            out,
+            "#[test]\nfn {}() {{\n    check_example({}, {}, {:?});\n}}\n",
+            name,
+            year,
+            day,
+            path.display().to_string()
+        )
+        .unwrap();
+    }
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    fs::write(out_dir.join("examples.rs"), out).unwrap();
+}
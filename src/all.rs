@@ -0,0 +1,197 @@
+//! Solving every day at once on a pool of worker threads
+//!
+//! Each worker takes the next unsolved day, reads its default input and solves both parts. Once
+//! every day is done the results are printed in day order, either as a table or as the same
+//! NDJSON lines `aoc run --format json` produces.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+
+use crate::cli::{self, Parts};
+use crate::error::{self, Error};
+use crate::output::{self, Format};
+use crate::{input, parse_day, Day, PartResult, Report, Result, DAYS};
+
+struct Options {
+    days: Vec<&'static Day>,
+    jobs: usize,
+    format: Format,
+    timeout: Option<Duration>,
+}
+
+fn parse_args(args: Vec<String>) -> Result<Options> {
+    let mut opts = Options {
+        days: Vec::new(),
+        jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+        format: Format::Text,
+        timeout: None,
+    };
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| anyhow!("missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "-j" | "--jobs" => opts.jobs = value()?.parse()?,
+            "--format" => opts.format = value()?.parse()?,
+            "--timeout" => opts.timeout = Some(cli::parse_duration(&value()?)?),
+            day => opts.days.push(parse_day(day)?),
+        }
+    }
+
+    if opts.days.is_empty() {
+        opts.days = DAYS.iter().collect();
+    }
+    opts.jobs = opts.jobs.clamp(1, opts.days.len());
+    Ok(opts)
+}
+
+/// Solve both parts of every day in `days` using `jobs` threads, giving each part `budget`
+///
+/// The results are in the same order as `days`.
+pub fn solve_all(
+    days: &[&'static Day],
+    jobs: usize,
+    budget: Option<Duration>,
+) -> Vec<Result<Report>> {
+    let next = AtomicUsize::new(0);
+    let results: Vec<_> = days.iter().map(|_| Mutex::new(None)).collect();
+
+    thread::scope(|s| {
+        for _ in 0..jobs.max(1) {
+            // As much stack as `aoc run` gives each part
+            thread::Builder::new()
+                .stack_size(8 << 20)
+                .spawn_scoped(s, || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(day) = days.get(i) else { break };
+                    let result = input::puzzle_input(None, day.year, day.day)
+                        .and_then(|input| (day.solve_within)(&input, Parts::Both, budget));
+                    *results[i].lock().unwrap() = Some(result);
+                })
+                .expect("failed to start a worker thread");
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|r| r.into_inner().unwrap().expect("every day was solved"))
+        .collect()
+}
+
+fn answer(report: &Report, part: u8) -> (String, String) {
+    let p = if part == 1 {
+        &report.part_one
+    } else {
+        &report.part_two
+    };
+    match p {
+        Some(PartResult::Answer(p)) => (p.answer.clone(), format!("{:.2?}", p.time)),
+        Some(PartResult::Timeout(budget)) => (String::from("timed out"), format!("{:.2?}", budget)),
+        Some(PartResult::Error(_)) => (String::from("failed"), String::from("-")),
+        None => (String::from("-"), String::from("-")),
+    }
+}
+
+fn print_table(days: &[&'static Day], results: &[Result<Report>]) {
+    let rows: Vec<_> = days
+        .iter()
+        .zip(results)
+        .map(|(day, result)| match result {
+            Ok(report) => {
+                let (one, one_time) = answer(report, 1);
+                let (two, two_time) = answer(report, 2);
+                let parse_time = format!("{:.2?}", report.parse_time);
+                (day.label(), Ok([one, two, parse_time, one_time, two_time]))
+            }
+            Err(e) => (day.label(), Err(format!("{:#}", e).replace('\n', " "))),
+        })
+        .collect();
+
+    let width = |i: usize, header: &str| {
+        rows.iter()
+            .filter_map(|(_, r)| r.as_ref().ok())
+            .map(|cols: &[String; 5]| cols[i].len())
+            .chain([header.len()])
+            .max()
+            .unwrap()
+    };
+    let w1 = width(0, "part 1");
+    let w2 = width(1, "part 2");
+
+    println!(
+        "{:>3}  {:<w1$}  {:<w2$}  {:>10}  {:>10}  {:>10}",
+        "day", "part 1", "part 2", "parse", "part 1", "part 2"
+    );
+    for (day, row) in rows {
+        match row {
+            Ok([one, two, parse_time, one_time, two_time]) => println!(
+                "{:>3}  {:<w1$}  {:<w2$}  {:>10}  {:>10}  {:>10}",
+                day, one, two, parse_time, one_time, two_time
+            ),
+            Err(e) => println!("{:>3}  error: {}", day, e),
+        }
+    }
+}
+
+/// Entry point for `aoc all [day...] [-j N] [--format text|json] [--timeout DURATION]`
+pub fn all_main(args: Vec<String>) -> Result<()> {
+    let opts = parse_args(args)?;
+
+    let start = Instant::now();
+    let results = solve_all(&opts.days, opts.jobs, opts.timeout);
+    let elapsed = start.elapsed();
+
+    match opts.format {
+        Format::Text => {
+            print_table(&opts.days, &results);
+            let total: Duration = results
+                .iter()
+                .flatten()
+                .flat_map(|r| r.timings())
+                .map(|(_, t)| t)
+                .sum();
+            println!(
+                "Ran {} day(s) in {:.2?} ({:.2?} of solving on {} thread(s))",
+                results.len(),
+                elapsed,
+                total,
+                opts.jobs
+            );
+        }
+        Format::Json => {
+            for (day, result) in opts.days.iter().zip(&results) {
+                let name = input::default_path(day.year, day.day);
+                match result {
+                    Ok(report) => {
+                        output::print_report(opts.format, day.year, day.day, &name, report)
+                    }
+                    Err(e) => output::print_error(opts.format, day.year, day.day, &name, e),
+                }
+            }
+        }
+    }
+
+    // A day with one failed part counts as failed, even though the other part's answer was shown
+    let exit_codes: Vec<_> = results
+        .iter()
+        .filter_map(|r| match r {
+            Ok(report) => report.failure().map(|e| error::exit_code_of(&e)),
+            Err(e) => Some(error::exit_code_of(e)),
+        })
+        .collect();
+    match exit_codes.first() {
+        Some(&exit_code) => Err(Error::InputsFailed {
+            failed: exit_codes.len(),
+            exit_code,
+        }
+        .into()),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,153 @@
+//! Counting heap allocations made while solving
+//!
+//! With the `alloc-stats` feature, a global allocator wrapping [`System`] counts every allocation
+//! and tracks how many bytes are live. [`measure`] uses those counters to report what a single
+//! phase (parsing or one part) allocated. Without the feature nothing is counted and [`measure`]
+//! returns `None`.
+//!
+//! The counters are kept per thread, so other phases running at the same time (as with
+//! `aoc all -j N`, or a part abandoned by `--timeout` that's still going) don't disturb them. The
+//! flip side is that allocations made by threads a phase spawns itself aren't counted.
+//!
+//! [`System`]: std::alloc::System
+
+use std::fmt::{self, Display};
+
+/// Heap usage of a single phase
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, counting each reallocation as one
+    pub allocations: u64,
+    /// Total bytes requested
+    pub bytes: u64,
+    /// Most bytes live at once, over and above what was live when the phase started
+    pub peak_bytes: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} bytes, {} bytes peak",
+            self.allocations, self.bytes, self.peak_bytes
+        )
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// One thread's counters
+    ///
+    /// `live` can go negative, when a thread frees memory another thread allocated.
+    #[derive(Clone, Copy)]
+    pub struct Counters {
+        pub allocations: u64,
+        pub bytes: u64,
+        pub live: i64,
+        pub peak: i64,
+    }
+
+    thread_local! {
+        // A `const` initialiser and no destructor, so using this never allocates
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters { allocations: 0, bytes: 0, live: 0, peak: 0 })
+        };
+    }
+
+    /// Update this thread's counters, unless it's being torn down
+    fn update(f: impl FnOnce(&mut Counters)) {
+        let _ = COUNTERS.try_with(|cell| {
+            let mut counters = cell.get();
+            f(&mut counters);
+            cell.set(counters);
+        });
+    }
+
+    /// This thread's counters, with the peak reset to what's live now
+    pub fn start() -> Counters {
+        COUNTERS.with(|cell| {
+            let mut counters = cell.get();
+            counters.peak = counters.live;
+            cell.set(counters);
+            counters
+        })
+    }
+
+    pub fn current() -> Counters {
+        COUNTERS.with(Cell::get)
+    }
+
+    fn allocated(size: usize) {
+        update(|c| {
+            c.allocations += 1;
+            c.bytes += size as u64;
+            c.live += size as i64;
+            c.peak = c.peak.max(c.live);
+        });
+    }
+
+    fn freed(size: usize) {
+        update(|c| c.live -= size as i64);
+    }
+
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            freed(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+}
+
+/// Run `f`, returning the heap usage of the current thread while it ran
+#[cfg(feature = "alloc-stats")]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    let start = counting::start();
+    let out = f();
+    let end = counting::current();
+
+    let stats = AllocStats {
+        allocations: end.allocations - start.allocations,
+        bytes: end.bytes - start.bytes,
+        peak_bytes: (end.peak - start.live).max(0) as u64,
+    };
+    (out, Some(stats))
+}
+
+/// Run `f`, returning its heap usage if allocations are being counted
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    (f(), None)
+}
@@ -0,0 +1,252 @@
+//! Repeated timing of solutions, with baselines for spotting regressions
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, ensure, Context};
+
+use crate::cli::Parts;
+use crate::{day_label, input, parse_day, parse_day_label, Day, Result, DAYS};
+
+/// Summary statistics over a set of timing samples
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Some(Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Benchmark results for every phase of a single day
+#[derive(Clone, Debug)]
+pub struct DayStats {
+    pub year: u64,
+    pub day: u64,
+    pub phases: Vec<(&'static str, Stats)>,
+}
+
+/// Parse and solve a day's input `iterations` times
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> Result<DayStats> {
+    ensure!(iterations > 0, "at least one iteration is required");
+
+    let mut samples: Vec<(&'static str, Vec<Duration>)> = Vec::new();
+    for _ in 0..iterations {
+        let report = (day.solve)(input, Parts::Both)?;
+        for (phase, time) in report.timings() {
+            match samples.iter_mut().find(|(p, _)| *p == phase) {
+                Some((_, s)) => s.push(time),
+                None => samples.push((phase, vec![time])),
+            }
+        }
+    }
+
+    let phases = samples
+        .into_iter()
+        .map(|(phase, mut s)| {
+            let stats = Stats::from_samples(&mut s).expect("samples are non-empty");
+            (phase, stats)
+        })
+        .collect();
+    Ok(DayStats {
+        year: day.year,
+        day: day.day,
+        phases,
+    })
+}
+
+/// Median timings from a previous run, keyed by year, day and phase
+#[derive(Clone, Debug, Default)]
+pub struct Baseline(HashMap<(u64, u64, String), Duration>);
+
+impl Baseline {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read baseline {}", path.display()))?;
+
+        let mut baseline = HashMap::new();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let mut field = || {
+                fields
+                    .next()
+                    .ok_or_else(|| anyhow!("invalid baseline line: {}", line))
+            };
+            let (year, day) = parse_day_label(field()?)?;
+            let phase = field()?.to_owned();
+            let nanos = field()?.parse()?;
+            baseline.insert((year, day, phase), Duration::from_nanos(nanos));
+        }
+        Ok(Baseline(baseline))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut entries: Vec<_> = self.0.iter().collect();
+        entries.sort();
+
+        let mut out = String::from("# day phase median_ns\n");
+        for ((year, day, phase), median) in entries {
+            writeln!(
+                out,
+                "{} {} {}",
+                day_label(*year, *day),
+                phase,
+                median.as_nanos()
+            )?;
+        }
+        fs::write(path, out)?;
+        Ok(())
+    }
+
+    pub fn get(&self, year: u64, day: u64, phase: &str) -> Option<Duration> {
+        self.0.get(&(year, day, phase.to_owned())).copied()
+    }
+
+    pub fn record(&mut self, stats: &DayStats) {
+        for (phase, s) in stats.phases.iter() {
+            self.0
+                .insert((stats.year, stats.day, (*phase).to_owned()), s.median);
+        }
+    }
+}
+
+/// How much slower `median` is than `baseline`, as a percentage
+fn slowdown(median: Duration, baseline: Duration) -> f64 {
+    let baseline = baseline.as_secs_f64().max(f64::EPSILON);
+    (median.as_secs_f64() / baseline - 1.0) * 100.0
+}
+
+struct Options {
+    days: Vec<&'static Day>,
+    iterations: usize,
+    threshold: f64,
+    baseline: PathBuf,
+    save: bool,
+}
+
+fn parse_args(args: Vec<String>) -> Result<Options> {
+    let mut opts = Options {
+        days: Vec::new(),
+        iterations: 10,
+        threshold: 10.0,
+        baseline: PathBuf::from("bench-baseline.txt"),
+        save: false,
+    };
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| anyhow!("missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "-n" | "--iterations" => opts.iterations = value()?.parse()?,
+            "--threshold" => opts.threshold = value()?.parse()?,
+            "--baseline" => opts.baseline = value()?.into(),
+            "--save" => opts.save = true,
+            day => opts.days.push(parse_day(day)?),
+        }
+    }
+
+    if opts.days.is_empty() {
+        opts.days = DAYS.iter().collect();
+    }
+    Ok(opts)
+}
+
+/// Entry point for `aoc bench [day...] [-n N] [--threshold PCT] [--baseline FILE] [--save]`
+///
+/// Compares against the baseline file if it exists, and writes a new one if it doesn't (or if
+/// `--save` is given).
+pub fn bench_main(args: Vec<String>) -> Result<()> {
+    let opts = parse_args(args)?;
+
+    let previous = if opts.baseline.exists() {
+        Some(Baseline::load(&opts.baseline)?)
+    } else {
+        None
+    };
+    let mut current = previous.clone().unwrap_or_default();
+    let mut regressions = 0;
+
+    println!(
+        "{:>3} {:<6} {:>12} {:>12} {:>12} {:>9}",
+        "day", "phase", "min", "median", "stddev", "change"
+    );
+    for day in opts.days {
+        let input = input::puzzle_input(None, day.year, day.day)?;
+        let stats = bench_day(day, &input, opts.iterations)?;
+
+        for (phase, s) in stats.phases.iter() {
+            let base = previous
+                .as_ref()
+                .and_then(|b| b.get(day.year, day.day, phase));
+            let change = match base {
+                Some(base) => {
+                    let pct = slowdown(s.median, base);
+                    let flag = if pct > opts.threshold {
+                        regressions += 1;
+                        " REGRESSION"
+                    } else {
+                        ""
+                    };
+                    format!("{:+8.1}%{}", pct, flag)
+                }
+                None => String::from("-"),
+            };
+            println!(
+                "{:>3} {:<6} {:>12} {:>12} {:>12} {:>9}",
+                day.label(),
+                phase,
+                format!("{:.2?}", s.min),
+                format!("{:.2?}", s.median),
+                format!("{:.2?}", s.stddev),
+                change
+            );
+        }
+        current.record(&stats);
+    }
+
+    if opts.save || previous.is_none() {
+        current.save(&opts.baseline)?;
+        println!("Saved baseline to {}", opts.baseline.display());
+    }
+
+    if regressions > 0 {
+        bail!(
+            "{} phase(s) regressed by more than {}%",
+            regressions,
+            opts.threshold
+        );
+    }
+    Ok(())
+}
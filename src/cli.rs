@@ -0,0 +1,166 @@
+//! Command line options shared by every day's binary
+//!
+//! ```text
+//! dayN [--part 1|2|both] [--input PATH]... [--format text|json] [--example]
+//!      [--trailing-newline one|none|keep] [--param NAME=VALUE]... [--timeout DURATION]
+//!      [--stream] [--watch] [PATH]
+//! ```
+//!
+//! `--input` may be given more than once to solve several inputs in one go, and a path of `-`
+//! reads from stdin. A bare positional path is treated the same as `--input`. With no inputs at
+//! all, the day's default input file (`input/YEAR/dayN.txt` or `input/dayN.txt`) is used.
+//!
+//! `--watch` keeps running, and solves the inputs again every time one of them changes.
+//! `--param` overrides one of the constants a day declares (see [`crate::Param`]), and
+//! `--trailing-newline` controls how the end of the input is normalised; see
+//! [`TrailingNewline`].
+//!
+//! `--timeout` gives each part a time budget, like `10s` or `500ms` (a bare number is seconds). A
+//! part that's still running when it runs out is abandoned and reported as timed out.
+//!
+//! `--stream` parses the input a line at a time instead of reading it all first, for days that
+//! support it; see [`crate::stream`].
+
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail};
+
+use crate::normalize::TrailingNewline;
+use crate::output::Format;
+use crate::{input, Result};
+
+pub const USAGE: &str = "usage: [--part 1|2|both] [--input PATH]... [--format text|json] \
+    [--example] [--trailing-newline one|none|keep] [--param NAME=VALUE]... [--timeout DURATION] \
+    [--stream] [--watch] [PATH]";
+
+/// Which parts of a puzzle to solve
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Parts {
+    One,
+    Two,
+    #[default]
+    Both,
+}
+
+impl Parts {
+    pub fn one(self) -> bool {
+        self != Parts::Two
+    }
+
+    pub fn two(self) -> bool {
+        self != Parts::One
+    }
+}
+
+impl FromStr for Parts {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Parts::One),
+            "2" => Ok(Parts::Two),
+            "both" => Ok(Parts::Both),
+            _ => bail!("invalid part: {} (expected 1, 2 or both)", s),
+        }
+    }
+}
+
+/// Parse a duration like `90s`, `500ms`, `2m` or `1.5` (in seconds)
+pub fn parse_duration(s: &str) -> Result<Duration> {
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| anyhow!("invalid duration: {}", s))?;
+    let secs = match unit {
+        "ms" => number / 1000.0,
+        "" | "s" => number,
+        "m" => number * 60.0,
+        _ => bail!("invalid duration: {} (expected a unit of ms, s or m)", s),
+    };
+    Duration::try_from_secs_f64(secs).map_err(|_| anyhow!("invalid duration: {}", s))
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub parts: Parts,
+    pub inputs: Vec<String>,
+    pub format: Format,
+    pub example: bool,
+    pub trailing_newline: TrailingNewline,
+    /// Parameter overrides, as name and value pairs
+    pub params: Vec<(String, String)>,
+    /// How long each part may run for before it's abandoned
+    pub timeout: Option<Duration>,
+    /// Parse the input a line at a time
+    pub stream: bool,
+    pub watch: bool,
+}
+
+impl Options {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+        let mut opts = Options::default();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_owned())),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| anyhow!("missing value for {}", flag))
+            };
+
+            match flag {
+                "--part" => opts.parts = value()?.parse()?,
+                "--input" => opts.inputs.push(value()?),
+                "--format" => opts.format = value()?.parse()?,
+                "--example" => opts.example = true,
+                "--watch" => opts.watch = true,
+                "--stream" => opts.stream = true,
+                "--trailing-newline" => opts.trailing_newline = value()?.parse()?,
+                "--timeout" => opts.timeout = Some(parse_duration(&value()?)?),
+                "--param" => {
+                    let param = value()?;
+                    let (name, value) = param
+                        .split_once('=')
+                        .ok_or_else(|| anyhow!("expected NAME=VALUE for --param, got {}", param))?;
+                    opts.params.push((name.to_owned(), value.to_owned()));
+                }
+                "-h" | "--help" => bail!("{}", USAGE),
+                "-" => opts.inputs.push(arg),
+                f if f.starts_with('-') => bail!("unknown option: {}\n{}", f, USAGE),
+                _ => opts.inputs.push(arg),
+            }
+        }
+
+        Ok(opts)
+    }
+
+    /// Every input to solve for `day`, where `None` is the default input file
+    pub fn sources(&self, year: u64, day: u64) -> Result<Vec<Option<String>>> {
+        let mut sources: Vec<_> = self.inputs.iter().cloned().map(Some).collect();
+        if self.example {
+            let examples = input::example_files(year, day)?;
+            if examples.is_empty() {
+                bail!("no examples found for day {}", crate::day_label(year, day));
+            }
+            sources.extend(
+                examples
+                    .into_iter()
+                    .map(|p: PathBuf| Some(p.display().to_string())),
+            );
+        }
+        if sources.is_empty() {
+            sources.push(None);
+        }
+        Ok(sources)
+    }
+}
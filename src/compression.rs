@@ -0,0 +1,78 @@
+//! Reading gzip and zstd compressed inputs
+//!
+//! Inputs can be kept compressed: any input, including stdin, that starts with the gzip or zstd
+//! magic bytes is decompressed as it's read, whatever it's called. When a day's default input
+//! file is missing, `dayN.txt.gz` and `dayN.txt.zst` are tried before downloading it.
+//!
+//! Decompression needs the `compression` feature (on by default). Without it, compressed inputs
+//! are still recognised, but reading one is an error.
+
+use std::io::{self, Read};
+
+/// Extensions of compressed input files, tried in this order
+pub const EXTENSIONS: &[&str] = &[".gz", ".zst"];
+
+/// A compression format that inputs can be stored in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    /// Recognise a format from the first few bytes of a file
+    pub fn detect(magic: &[u8]) -> Option<Self> {
+        if magic.starts_with(&[0x1f, 0x8b]) {
+            Some(Compression::Gzip)
+        } else if magic.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Some(Compression::Zstd)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "compression")]
+    fn decoder<'a, R: Read + 'a>(self, reader: R) -> io::Result<Box<dyn Read + 'a>> {
+        Ok(match self {
+            // Concatenated gzip members are read as one, like `zcat` does
+            Compression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(reader)),
+            Compression::Zstd => Box::new(zstd::stream::read::Decoder::new(reader)?),
+        })
+    }
+
+    #[cfg(not(feature = "compression"))]
+    fn decoder<'a, R: Read + 'a>(self, _reader: R) -> io::Result<Box<dyn Read + 'a>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("{:?} compressed input needs the compression feature", self),
+        ))
+    }
+}
+
+/// Read the first bytes of `reader`, returning them and the compression format they indicate
+///
+/// The bytes have already been consumed, so they have to be read again before the rest of the
+/// reader.
+fn sniff<R: Read>(reader: &mut R) -> io::Result<(Vec<u8>, Option<Compression>)> {
+    let mut magic = Vec::with_capacity(4);
+    reader.take(4).read_to_end(&mut magic)?;
+    let compression = Compression::detect(&magic);
+    Ok((magic, compression))
+}
+
+/// Wrap `reader` so that it's decompressed as it's read, if it's compressed
+pub fn decompress<'a, R: Read + 'a>(mut reader: R) -> io::Result<Box<dyn Read + 'a>> {
+    let (magic, compression) = sniff(&mut reader)?;
+    let reader = io::Cursor::new(magic).chain(reader);
+    match compression {
+        Some(compression) => compression.decoder(reader),
+        None => Ok(Box::new(reader)),
+    }
+}
+
+/// Whether the file at `reader` is compressed, leaving it where it started
+pub fn is_compressed<R: Read + io::Seek>(reader: &mut R) -> io::Result<bool> {
+    let (_, compression) = sniff(reader)?;
+    reader.rewind()?;
+    Ok(compression.is_some())
+}
@@ -0,0 +1,226 @@
+//! Checking clever solutions against brute-force ones
+//!
+//! Some days lean on shortcuts that are hard to trust by reading them: a closed form, memoised
+//! expansion, a rotating array of counters, dynamic programming run backwards. Those days also
+//! implement [`Reference`], a solution that does the obvious thing however slowly, and
+//! `aoc crosscheck` runs both on the same input and reports any disagreement.
+//!
+//! Brute force can't always finish (256 days of lanternfish won't fit in memory), so a reference
+//! may give up with an error. That part is then reported as unchecked rather than as a mismatch;
+//! use `--param` to shrink the problem until the reference can cope.
+
+use std::fmt::{self, Display};
+
+use anyhow::{anyhow, bail};
+
+use crate::generate::{find_generator, Rng};
+use crate::{day14, day17, day21, day6};
+use crate::{day_label, input, parse_day_label, Params, Result, Solution};
+
+/// A straightforward solution to check a day's real one against
+pub trait Reference: Solution {
+    fn reference_part_one(input: Self::Input, params: &Params) -> Result<Self::Output1>;
+    fn reference_part_two(input: Self::Input, params: &Params) -> Result<Self::Output2>;
+}
+
+/// Parameter overrides, as name and value pairs
+type Overrides = [(String, String)];
+
+/// One part's answer next to the reference answer
+#[derive(Debug)]
+pub struct PartCheck {
+    pub part: u8,
+    pub answer: Result<String>,
+    pub reference: Result<String>,
+}
+
+impl PartCheck {
+    /// Whether the solution got something other than the reference answer
+    ///
+    /// A part the reference couldn't solve isn't a disagreement.
+    pub fn disagrees(&self) -> bool {
+        match (&self.answer, &self.reference) {
+            (Ok(answer), Ok(reference)) => answer != reference,
+            (Err(_), Ok(_)) => true,
+            (_, Err(_)) => false,
+        }
+    }
+}
+
+impl Display for PartCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part {}: ", self.part)?;
+        match (&self.answer, &self.reference) {
+            (Ok(a), Ok(r)) if a == r => write!(f, "{} (agrees)", a),
+            (Ok(a), Ok(r)) => write!(f, "DISAGREE: {}, reference {}", a, r),
+            (Err(e), Ok(r)) => write!(f, "DISAGREE: failed ({:#}), reference {}", e, r),
+            (Ok(a), Err(e)) => write!(f, "{} (unchecked: reference failed: {:#})", a, e),
+            (Err(e), Err(re)) => write!(f, "failed ({:#}), reference failed ({:#})", e, re),
+        }
+    }
+}
+
+/// Parse `input` once and solve both parts with the solution and the reference
+pub fn cross_check<S: Reference>(input: &str, params: &Params) -> Result<[PartCheck; 2]> {
+    let input = S::parse(input)?;
+    Ok([
+        PartCheck {
+            part: 1,
+            answer: string(S::part_one(input.clone(), params)),
+            reference: string(S::reference_part_one(input.clone(), params)),
+        },
+        PartCheck {
+            part: 2,
+            answer: string(S::part_two(input.clone(), params)),
+            reference: string(S::reference_part_two(input, params)),
+        },
+    ])
+}
+
+fn string<T: Display>(result: Result<T>) -> Result<String> {
+    result.map(|answer| answer.to_string())
+}
+
+/// A day with a reference solution, with its types erased like [`crate::Day`]
+#[derive(Clone, Copy)]
+pub struct Checked {
+    pub year: u64,
+    pub day: u64,
+    pub check: fn(&str, &Overrides) -> Result<[PartCheck; 2]>,
+}
+
+fn check<S: Reference>(input: &str, overrides: &Overrides) -> Result<[PartCheck; 2]> {
+    cross_check::<S>(input, &Params::new(S::PARAMS, overrides)?)
+}
+
+macro_rules! references {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        /// Every day with a reference solution, in order
+        pub static REFERENCES: &[Checked] = &[
+            $(Checked {
+                year: <$module::$solution as Solution>::YEAR,
+                day: <$module::$solution as Solution>::DAY,
+                check: check::<$module::$solution>,
+            }),*
+        ];
+    };
+}
+
+references![day6::Day6, day14::Day14, day17::Day17, day21::Day21];
+
+/// Look up the reference solution for a day
+pub fn find_reference(year: u64, day: u64) -> Result<&'static Checked> {
+    REFERENCES
+        .iter()
+        .find(|c| c.year == year && c.day == day)
+        .ok_or_else(|| {
+            let days: Vec<_> = REFERENCES
+                .iter()
+                .map(|c| day_label(c.year, c.day))
+                .collect();
+            anyhow!(
+                "no reference solution for day {} (there are references for days {})",
+                day_label(year, day),
+                days.join(", ")
+            )
+        })
+}
+
+/// Parse `N` or `A..B` into a range of seeds
+fn parse_seeds(s: &str) -> Result<std::ops::Range<u64>> {
+    match s.split_once("..") {
+        Some((start, end)) => Ok(start.parse()?..end.parse()?),
+        None => {
+            let seed = s.parse()?;
+            Ok(seed..seed + 1)
+        }
+    }
+}
+
+/// Entry point for
+/// `aoc crosscheck <day> [PATH | --generate SEEDS [--size N]] [--param NAME=VALUE]...`
+///
+/// `SEEDS` is a single seed or a range like `0..100`, each of which is used to generate an input.
+pub fn crosscheck_main(args: Vec<String>) -> Result<()> {
+    let mut day = None;
+    let mut path = None;
+    let mut seeds = None;
+    let mut size = None;
+    let mut params = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| anyhow!("missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--generate" => seeds = Some(parse_seeds(&value()?)?),
+            "--size" => size = Some(value()?.parse()?),
+            "--param" => {
+                let param = value()?;
+                let (name, value) = param
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("expected NAME=VALUE, got {}", param))?;
+                params.push((name.to_owned(), value.to_owned()));
+            }
+            label if day.is_none() => day = Some(parse_day_label(label)?),
+            other if path.is_none() => path = Some(other.to_owned()),
+            _ => bail!("unexpected argument: {}", arg),
+        }
+    }
+    let (year, day) = day.ok_or_else(|| {
+        anyhow!("usage: aoc crosscheck <day> [PATH | --generate SEEDS [--size N]] [--param NAME=VALUE]...")
+    })?;
+    let checked = find_reference(year, day)?;
+
+    let inputs: Vec<(String, String)> = match seeds {
+        Some(seeds) => {
+            let generator = find_generator(year, day)?;
+            let size = generator.size(size)?;
+            seeds
+                .map(|seed| {
+                    let input = (generator.generate)(&mut Rng::new(seed), size);
+                    (format!("seed {}", seed), input)
+                })
+                .collect()
+        }
+        None => {
+            let name = path
+                .clone()
+                .unwrap_or_else(|| input::default_path(year, day));
+            vec![(name, input::puzzle_input(path.as_deref(), year, day)?)]
+        }
+    };
+
+    let mut disagreements = Vec::new();
+    for (name, input) in &inputs {
+        let parts = (checked.check)(input, &params)?;
+        let disagrees = parts.iter().any(PartCheck::disagrees);
+        if inputs.len() == 1 || disagrees {
+            println!("== {} ==", name);
+            for part in &parts {
+                println!("{}", part);
+            }
+        }
+        if disagrees {
+            disagreements.push(name);
+        }
+    }
+
+    if inputs.len() > 1 {
+        println!(
+            "Checked {} input(s): {} disagreed",
+            inputs.len(),
+            disagreements.len()
+        );
+    }
+    if let Some(first) = disagreements.first() {
+        bail!(
+            "the solution disagrees with the reference on {} input(s), first on {}",
+            disagreements.len(),
+            first
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::collections::VecDeque;
+
+use crate::stream::LineParser;
+use crate::{Params, Result, Solution};
+
+use nom::character::complete::{line_ending, u64};
+use nom::combinator::eof;
+use nom::multi::many0;
+use nom::multi::separated_list1;
+use nom::sequence::preceded;
+use nom::sequence::terminated;
+use nom::IResult;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 1;
+
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    const LINES: Option<LineParser<Self::Input>> = Some(LineParser {
+        empty: Vec::new,
+        line: |nums, line| {
+            nums.push(line.trim().parse()?);
+            Ok(())
+        },
+    });
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_nums(input)
+    }
+
+    fn part_one(input: Self::Input, _params: &Params) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: Self::Input, _params: &Params) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(nums: Vec<u64>) -> Result<u64> {
+    let mut latest = nums.first().cloned().unwrap_or(0u64);
+    let mut count = 0;
+
+    for i in nums.iter().cloned().skip(1) {
+        if i > latest {
+            count += 1;
+        }
+        latest = i;
+    }
+
+    Ok(count)
+}
+
+fn part_two(nums: Vec<u64>) -> Result<u64> {
+    let mut latest: VecDeque<u64> = nums.get(..=2).unwrap_or(&[]).iter().cloned().collect();
+    let mut count = 0;
+
+    for i in nums.iter().cloned().skip(3) {
+        if i > latest.pop_front().unwrap() {
+            count += 1;
+        }
+        latest.push_back(i);
+    }
+    Ok(count)
+}
+
+fn parse_nums(s: &str) -> Result<Vec<u64>> {
+    fn inner(s: &str) -> IResult<&str, Vec<u64>> {
+        terminated(
+            separated_list1(line_ending, u64),
+            preceded(many0(line_ending), eof),
+        )(s)
+    }
+    match inner(s) {
+        Ok((_s, v)) => Ok(v),
+        Err(e) => Err(e.to_owned().into()),
+    }
+}
@@ -0,0 +1,136 @@
+use crate::parse_error::ParseError;
+use crate::{Param, Params, Result, Solution};
+use anyhow::{anyhow, ensure};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 11;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "steps",
+        default: "100",
+        help: "steps to count flashes over in part one",
+    }];
+
+    type Input = Cavern;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines: Vec<_> = input.trim().lines().map(str::trim).collect();
+        let width = lines.first().map_or(0, |l| l.len());
+        ensure!(width > 0, "empty grid");
+        ensure!(
+            lines.iter().all(|l| l.len() == width),
+            "every row must be {} octopuses wide",
+            width
+        );
+        let mut energy = Vec::with_capacity(width * lines.len());
+        for line in &lines {
+            energy.extend(ParseError::digits(input, line)?);
+        }
+        Ok(Cavern { width, energy })
+    }
+
+    fn part_one(input: Self::Input, params: &Params) -> Result<Self::Output1> {
+        part_one(input, params.get("steps")?)
+    }
+
+    fn part_two(input: Self::Input, _params: &Params) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(mut grid: Cavern, steps: usize) -> Result<usize> {
+    Ok((0..steps).map(|_| grid.step()).sum())
+}
+
+fn part_two(grid: Cavern) -> Result<usize> {
+    first_synchronized_step(grid).ok_or_else(|| anyhow!("the octopuses never all flash at once"))
+}
+
+/// The first step on which every octopus flashes, or `None` if that never happens
+///
+/// Randomly generated grids can settle into a cycle without ever synchronising. Cycles are found
+/// with Brent's algorithm: the grid is compared against a saved copy that's moved forward every
+/// power of two steps.
+pub fn first_synchronized_step(mut grid: Cavern) -> Option<usize> {
+    let mut saved = grid.energy.clone();
+    let mut power = 1;
+    for step in 1.. {
+        if grid.step() == grid.energy.len() {
+            return Some(step);
+        }
+        if grid.energy == saved {
+            return None;
+        }
+        if step == power {
+            saved.clone_from(&grid.energy);
+            power *= 2;
+        }
+    }
+    unreachable!()
+}
+
+#[derive(Clone, Debug)]
+pub struct Cavern {
+    width: usize,
+    energy: Vec<u8>,
+}
+
+impl Cavern {
+    /// Run a single step, returning the number of flashes
+    pub fn step(&mut self) -> usize {
+        self.increment_all();
+        let mut total = 0;
+        loop {
+            let flashes = self.calc_flashes();
+            if flashes == 0 {
+                return total;
+            }
+            total += flashes;
+        }
+    }
+
+    fn increment_all(&mut self) {
+        for oct in self.energy.iter_mut() {
+            *oct += 1;
+        }
+    }
+
+    fn calc_flashes(&mut self) -> usize {
+        let mut count = 0;
+        for i in 0..self.energy.len() {
+            if self.energy[i] > 9 {
+                count += 1;
+                self.energy[i] = 0;
+                for i in self.neighbors(i).into_iter().flatten() {
+                    if self.energy[i] != 0 {
+                        self.energy[i] += 1;
+                    }
+                }
+            }
+        }
+        count
+    }
+
+    fn neighbors(&self, idx: usize) -> [Option<usize>; 8] {
+        let width = self.width;
+        let len = self.energy.len();
+        let left = |i: usize| (!i.is_multiple_of(width)).then(|| i - 1);
+        let right = |i: usize| (!(i + 1).is_multiple_of(width)).then_some(i + 1);
+        let up = |i: usize| i.checked_sub(width);
+        let down = |i: usize| Some(i + width).filter(|&j| j < len);
+        [
+            left(idx),
+            right(idx),
+            up(idx),
+            down(idx),
+            up(idx).and_then(left),
+            up(idx).and_then(right),
+            down(idx).and_then(left),
+            down(idx).and_then(right),
+        ]
+    }
+}
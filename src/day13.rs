@@ -0,0 +1,168 @@
+use std::fmt::Display;
+
+use crate::parse_error::ParseError;
+use crate::{Params, Result, Solution};
+use nom::Finish;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 13;
+
+    type Input = (Paper, Vec<Fold>);
+    type Output1 = usize;
+    type Output2 = Folded;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rest, out) = parse::input(input)
+            .finish()
+            .map_err(|e| ParseError::from_nom(input, e))?;
+        ParseError::check_rest(input, rest, parse::fold)?;
+        Ok(out)
+    }
+
+    fn part_one(input: Self::Input, _params: &Params) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: Self::Input, _params: &Params) -> Result<Self::Output2> {
+        part_two(input)
+    }
+
+    fn picture(answer: &Self::Output2) -> Option<String> {
+        Some(answer.0.to_string())
+    }
+}
+
+fn part_one(input: (Paper, Vec<Fold>)) -> Result<usize> {
+    let (mut paper, folds) = input;
+    paper.fold(folds.first().unwrap().clone());
+
+    Ok(paper.0.len())
+}
+
+fn part_two(input: (Paper, Vec<Fold>)) -> Result<Folded> {
+    let (mut paper, folds) = input;
+
+    for f in folds {
+        paper.fold(f);
+    }
+
+    Ok(Folded(paper))
+}
+
+/// The fully folded paper, which shows as the number of dots left
+///
+/// The real answer is read off the picture, which [`Solution::picture`] draws.
+#[derive(Debug, Clone)]
+pub struct Folded(pub Paper);
+
+impl Display for Folded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0 .0.len())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Fold {
+    Up(u32),
+    Left(u32),
+}
+
+#[derive(Debug, Clone)]
+pub struct Paper(pub Vec<(u32, u32)>);
+
+impl Paper {
+    /// Fold the paper, merging any dots that end up on top of each other
+    pub fn fold(&mut self, fold: Fold) {
+        for item in self.0.iter_mut() {
+            let (x, y) = *item;
+            match fold {
+                Fold::Up(y0) if y > y0 => {
+                    item.1 = y0 - (y - y0);
+                }
+                Fold::Left(x0) if x > x0 => {
+                    item.0 = x0 - (x - x0);
+                }
+                _ => {}
+            }
+        }
+        self.0.sort_unstable();
+        self.0.dedup();
+    }
+}
+
+/// Draws the dots as `#`s on rows of `.`s, without a newline after the last row
+impl Display for Paper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut width = 0;
+        let mut lines = 0;
+        for &(x, y) in &self.0 {
+            if x > width {
+                width = x
+            }
+            if y > lines {
+                lines = y
+            }
+        }
+        let width = (width + 2) as usize;
+        let lines = (lines + 1) as usize;
+
+        let mut out = vec![b'.'; width * lines];
+        for &(x, y) in &self.0 {
+            out[(y as usize) * width + (x as usize)] = b'#';
+        }
+        for i in 0..lines {
+            out[i * width + (width - 1)] = b'\n';
+        }
+
+        out.pop();
+        write!(f, "{}", unsafe { std::str::from_utf8_unchecked(&out) })
+    }
+}
+
+mod parse {
+    use super::*;
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::line_ending,
+        combinator::eof,
+        multi::many0,
+        sequence::{delimited, separated_pair, terminated},
+        IResult,
+    };
+
+    fn point(i: &str) -> IResult<&str, (u32, u32)> {
+        terminated(
+            separated_pair(
+                nom::character::complete::u32,
+                tag(","),
+                nom::character::complete::u32,
+            ),
+            line_ending,
+        )(i)
+    }
+
+    fn paper(i: &str) -> IResult<&str, Paper> {
+        let (i, vec) = many0(point)(i)?;
+        Ok((i, Paper(vec)))
+    }
+
+    pub(super) fn fold(i: &str) -> IResult<&str, Fold> {
+        let (i, letter) = delimited(tag("fold along "), alt((tag("x"), tag("y"))), tag("="))(i)?;
+        // The last fold's newline is optional, for `--trailing-newline none`
+        let (i, val) = terminated(nom::character::complete::u32, alt((line_ending, eof)))(i)?;
+        let fold = match letter {
+            "y" => Fold::Up(val),
+            "x" => Fold::Left(val),
+            _ => unreachable!(),
+        };
+        Ok((i, fold))
+    }
+
+    pub(super) fn input(i: &str) -> IResult<&str, (Paper, Vec<Fold>)> {
+        separated_pair(paper, line_ending, many0(fold))(i)
+    }
+}
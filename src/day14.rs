@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use crate::crosscheck::Reference;
+use crate::parse_error::ParseError;
+use crate::{Param, Params, Result, Solution};
+use anyhow::ensure;
+use nom::Finish;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 14;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "part1_steps",
+            default: "10",
+            help: "pair insertion steps in part one",
+        },
+        Param {
+            name: "part2_steps",
+            default: "40",
+            help: "pair insertion steps in part two",
+        },
+    ];
+
+    type Input = (PolymerTemplate, PairInsertionRules);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rest, out) = parse::input(input)
+            .finish()
+            .map_err(|e| ParseError::from_nom(input, e))?;
+        ParseError::check_rest(input, rest, parse::pair_insertion)?;
+        Ok(out)
+    }
+
+    fn part_one(input: Self::Input, params: &Params) -> Result<Self::Output1> {
+        let (template, rules) = input;
+        solve(template, rules, params.get("part1_steps")?)
+    }
+
+    fn part_two(input: Self::Input, params: &Params) -> Result<Self::Output2> {
+        let (template, rules) = input;
+        solve(template, rules, params.get("part2_steps")?)
+    }
+}
+
+fn solve(template: PolymerTemplate, rules: PairInsertionRules, steps: usize) -> Result<usize> {
+    // Every count is at most the length of the polymer, which doubles (less one) each step
+    let length = u32::try_from(steps)
+        .ok()
+        .and_then(|steps| 2usize.checked_pow(steps))
+        .and_then(|n| n.checked_mul(template.0.len().saturating_sub(1)))
+        .and_then(|n| n.checked_add(1));
+    ensure!(
+        length.is_some(),
+        "the polymer is too long to count after {} steps",
+        steps
+    );
+
+    let mut memory = HashMap::new();
+    let mut counts = HashMap::new();
+
+    for pair in template.0.windows(2) {
+        add_counts(
+            &mut counts,
+            get_counts(pair.try_into().unwrap(), steps, &rules, &mut memory),
+        );
+    }
+
+    if let Some(poly) = template.0.last() {
+        *counts.entry(*poly).or_insert(0) += 1;
+    }
+
+    let mut counts: Vec<usize> = counts.values().copied().collect();
+
+    counts.sort_unstable();
+
+    let val = match (counts.first(), counts.last()) {
+        (Some(first), Some(last)) => last - first,
+        _ => 0,
+    };
+    Ok(val)
+}
+
+fn get_counts(
+    pair: [u8; 2],
+    steps: usize,
+    rules: &PairInsertionRules,
+    memory: &mut HashMap<([u8; 2], usize), HashMap<u8, usize>>,
+) -> HashMap<u8, usize> {
+    if let Some(val) = memory.get(&(pair, steps)) {
+        return val.clone();
+    }
+
+    let poly = match (steps, rules.0.get(&pair)) {
+        (0, _) | (_, None) => return [(pair[0], 1)].into_iter().collect(),
+        (_, Some(poly)) => *poly,
+    };
+
+    let sub_pair = [pair[0], poly];
+    let mut counts = get_counts(sub_pair, steps - 1, rules, memory);
+
+    let sub_pair = [poly, pair[1]];
+
+    add_counts(&mut counts, get_counts(sub_pair, steps - 1, rules, memory));
+
+    memory.insert((pair, steps), counts.clone());
+
+    counts
+}
+
+fn add_counts(dest: &mut HashMap<u8, usize>, src: HashMap<u8, usize>) {
+    for (k, v) in src.into_iter() {
+        *dest.entry(k).or_insert(0) += v;
+    }
+}
+
+impl Reference for Day14 {
+    fn reference_part_one(input: Self::Input, params: &Params) -> Result<Self::Output1> {
+        let (template, rules) = input;
+        expand(template, rules, params.get("part1_steps")?)
+    }
+
+    fn reference_part_two(input: Self::Input, params: &Params) -> Result<Self::Output2> {
+        let (template, rules) = input;
+        expand(template, rules, params.get("part2_steps")?)
+    }
+}
+
+/// Longer polymers than this won't fit comfortably in memory
+const MAX_POLYMER: usize = 100_000_000;
+
+/// Build the whole polymer one step at a time, then count its elements
+fn expand(template: PolymerTemplate, rules: PairInsertionRules, steps: usize) -> Result<usize> {
+    let mut polymer = template.0;
+    for _ in 0..steps {
+        ensure!(
+            polymer.len() * 2 <= MAX_POLYMER,
+            "the polymer would be longer than {} elements",
+            MAX_POLYMER
+        );
+        let mut next = Vec::with_capacity(polymer.len() * 2);
+        for pair in polymer.windows(2) {
+            next.push(pair[0]);
+            if let Some(&insert) = rules.0.get(&[pair[0], pair[1]]) {
+                next.push(insert);
+            }
+        }
+        next.extend(polymer.last());
+        polymer = next;
+    }
+
+    let mut counts = [0usize; 256];
+    for element in polymer {
+        counts[usize::from(element)] += 1;
+    }
+    let present = counts.iter().copied().filter(|&c| c > 0);
+    Ok(present.clone().max().unwrap_or(0) - present.min().unwrap_or(0))
+}
+
+#[derive(Debug, Clone)]
+pub struct PolymerTemplate(pub Vec<u8>);
+
+/// The element inserted between each pair of elements
+#[derive(Debug, Clone)]
+pub struct PairInsertionRules(pub HashMap<[u8; 2], u8>);
+
+mod parse {
+    use nom::{
+        branch::alt,
+        bytes::complete::{tag, take, take_while},
+        character::{complete::line_ending, is_alphanumeric},
+        combinator::{eof, map},
+        multi::many0,
+        sequence::{separated_pair, terminated},
+        IResult,
+    };
+
+    use super::*;
+
+    fn template(i: &[u8]) -> IResult<&[u8], PolymerTemplate> {
+        map(
+            terminated(take_while(is_alphanumeric), line_ending),
+            |p: &[u8]| PolymerTemplate(p.to_vec()),
+        )(i)
+    }
+
+    pub(super) fn pair_insertion(i: &[u8]) -> IResult<&[u8], ([u8; 2], u8)> {
+        let (i, (pair, insert)) = terminated(
+            separated_pair(take(2usize), tag(" -> "), take(1usize)),
+            // The last rule's newline is optional, for `--trailing-newline none`
+            alt((line_ending, eof)),
+        )(i)?;
+        Ok((i, (pair.try_into().unwrap(), insert[0])))
+    }
+
+    pub(super) fn input(i: &str) -> IResult<&[u8], (PolymerTemplate, PairInsertionRules)> {
+        separated_pair(
+            template,
+            line_ending,
+            map(many0(pair_insertion), |v| {
+                PairInsertionRules(v.into_iter().collect())
+            }),
+        )(i.as_bytes())
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{Param, Params, Result, Solution};
+use anyhow::{anyhow, ensure};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 15;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "tiles",
+        default: "5",
+        help: "how many times the cave is repeated in each direction in part two",
+    }];
+
+    type Input = Cavern;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::input(input)
+    }
+
+    fn part_one(input: Self::Input, _params: &Params) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: Self::Input, params: &Params) -> Result<Self::Output2> {
+        part_two(input, params.get("tiles")?)
+    }
+}
+
+fn part_one(cavern: Cavern) -> Result<u64> {
+    Ok(cavern.find_min_risk_level())
+}
+
+fn part_two(mut cavern: Cavern, tiles: usize) -> Result<u64> {
+    ensure!(tiles > 0, "the cave must be repeated at least once");
+    cavern.expand(tiles, tiles)?;
+    Ok(cavern.find_min_risk_level())
+}
+
+#[derive(Debug, Clone)]
+pub struct Cavern {
+    width: usize,
+    risk_levels: Vec<u8>,
+}
+
+impl Cavern {
+    /// The lowest total risk of any path from the top left to the bottom right
+    pub fn find_min_risk_level(&self) -> u64 {
+        let mut lowest = vec![None; self.risk_levels.len()];
+
+        lowest[0] = Some(0);
+
+        loop {
+            let mut changed = false;
+
+            for i in 0..lowest.len() {
+                if let Some(min_neighbor) = find_min_neighbor(&lowest, self.width, i) {
+                    let new_low = min_neighbor + u64::from(self.risk_levels[i]);
+                    let is_lower = match lowest[i] {
+                        Some(low) => new_low < low,
+                        None => true,
+                    };
+
+                    if is_lower {
+                        lowest[i] = Some(new_low);
+                        changed = true;
+                    }
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        lowest.last().unwrap().unwrap()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.risk_levels.len() / self.width
+    }
+
+    /// Tile the map `rows` times down and `cols` times across, with risk increasing by one for
+    /// every tile away from the original
+    ///
+    /// Fails if the tiled map would have more cells than can be counted.
+    pub fn expand(&mut self, rows: usize, cols: usize) -> Result<()> {
+        let cells = rows
+            .checked_mul(cols)
+            .and_then(|tiles| tiles.checked_mul(self.risk_levels.len()))
+            .ok_or_else(|| anyhow!("{}x{} tiles is too many", rows, cols))?;
+        let mut new_map = vec![0u8; cells];
+
+        let rstride = self.width * cols;
+        let xstride = self.risk_levels.len() * cols;
+        let ystride = self.width;
+        for r in 0..self.height() {
+            for c in 0..self.width {
+                for x in 0..rows {
+                    for y in 0..cols {
+                        let old_val = self.risk_levels[r * self.width + c];
+                        let new_val = ((usize::from(old_val - 1) + x + y) % 9) as u8 + 1;
+                        new_map[x * xstride + y * ystride + r * rstride + c] = new_val;
+                    }
+                }
+            }
+        }
+        self.width *= cols;
+        self.risk_levels = new_map;
+        Ok(())
+    }
+}
+
+fn find_min_neighbor(lowest: &[Option<u64>], width: usize, idx: usize) -> Option<u64> {
+    let left = |i| if i % width == 0 { None } else { Some(i - 1) };
+    let right = |i| {
+        if (i + 1) % width == 0 {
+            None
+        } else {
+            Some(i + 1)
+        }
+    };
+    let up = |i| if i < width { None } else { Some(i - width) };
+    let down = |i| {
+        if i + width < lowest.len() {
+            Some(i + width)
+        } else {
+            None
+        }
+    };
+
+    let neighbors = [
+        left(idx).and_then(|i| lowest[i]),
+        right(idx).and_then(|i| lowest[i]),
+        up(idx).and_then(|i| lowest[i]),
+        down(idx).and_then(|i| lowest[i]),
+    ];
+
+    neighbors.into_iter().flatten().min()
+}
+
+mod parse {
+    use super::*;
+    use crate::parse_error::ParseError;
+    use anyhow::anyhow;
+
+    pub(super) fn input(s: &str) -> Result<Cavern> {
+        let mut risk_levels = Vec::new();
+        let mut width = None;
+        for line in s.trim().lines() {
+            let bytes = line.trim().bytes();
+            if let Some(w) = width {
+                if w != bytes.len() {
+                    return Err(anyhow!("Invalid line length"));
+                }
+            } else {
+                width = Some(bytes.len());
+            }
+
+            let line = line.trim();
+            let levels = ParseError::digits(s, line)?;
+            if let Some(i) = levels.iter().position(|&l| l == 0) {
+                return Err(ParseError::at(s, &line[i..], "risk levels go from 1 to 9").into());
+            }
+            risk_levels.extend(levels);
+        }
+
+        Ok(Cavern {
+            width: width.unwrap_or(1),
+            risk_levels,
+        })
+    }
+}
@@ -0,0 +1,261 @@
+use std::str::FromStr;
+
+use crate::parse_error::ParseError;
+use crate::{Params, Result, Solution};
+use nom::error::{Error, ErrorKind};
+use nom::Finish;
+use nom::{sequence::tuple, IResult};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 16;
+
+    type Input = Vec<u8>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rest, out) = parse::input(input)
+            .finish()
+            .map_err(|e| ParseError::from_nom(input, e))?;
+        ParseError::check_rest(input, rest, parse::hex_byte)?;
+        Ok(out)
+    }
+
+    fn part_one(input: Self::Input, _params: &Params) -> Result<Self::Output1> {
+        Ok(Packet::decode(&input)?.version_sum())
+    }
+
+    fn part_two(input: Self::Input, _params: &Params) -> Result<Self::Output2> {
+        Ok(Packet::decode(&input)?.value())
+    }
+}
+
+/// A BITS packet, with any subpackets it contains
+#[derive(Clone, Debug)]
+pub struct Packet {
+    pub version: u8,
+    pub data: PacketData,
+}
+
+impl Packet {
+    /// Decode the outermost packet of a transmission, given as bytes
+    ///
+    /// Errors point at the hex digit where decoding went wrong, as if the transmission had been
+    /// written out in hex again.
+    pub fn decode(transmission: &[u8]) -> Result<Self> {
+        let (_, pkt) = nom::bits::bits(Packet::from_transmission)(transmission)
+            .finish()
+            .map_err(|e: Error<&[u8]>| {
+                let hex: String = transmission.iter().map(|b| format!("{:02X}", b)).collect();
+                let at = 2 * transmission.len().saturating_sub(e.input.len());
+                let rest = &hex[at.min(hex.len())..];
+                match e.code {
+                    ErrorKind::Eof => {
+                        ParseError::at(&hex, rest, "transmission ends partway through a packet")
+                    }
+                    ErrorKind::LengthValue => {
+                        ParseError::at(&hex, rest, "subpackets don't fit in their length")
+                    }
+                    ErrorKind::Verify => {
+                        ParseError::at(&hex, rest, "wrong number of subpackets for the operator")
+                    }
+                    code => ParseError::from_nom(&hex, Error::new(rest, code)),
+                }
+            })?;
+        Ok(pkt)
+    }
+
+    fn from_transmission(input: (&[u8], usize)) -> IResult<(&[u8], usize), Self> {
+        use nom::bits::complete::take;
+        let (input, version) = take(3usize)(input)?;
+        let (input, type_) = take(3usize)(input)?;
+        let (input, data) = match type_ {
+            4 => PacketData::literal(input)?,
+            _ => PacketData::operator(type_, input)?,
+        };
+
+        Ok((input, Packet { version, data }))
+    }
+
+    /// The version numbers of this packet and every packet inside it, added up
+    pub fn version_sum(&self) -> u64 {
+        let sub = match self.data {
+            PacketData::Operator { ref subpackets, .. } => {
+                subpackets.iter().map(|s| s.version_sum()).sum()
+            }
+            _ => 0,
+        };
+        sub + u64::from(self.version)
+    }
+
+    /// Evaluate the expression this packet represents
+    pub fn value(&self) -> u64 {
+        match self.data {
+            PacketData::Literal { ref nibbles } => {
+                let mut acc = 0;
+                for n in nibbles {
+                    acc <<= 4;
+                    acc += u64::from(*n);
+                }
+                acc
+            }
+            PacketData::Operator { op, ref subpackets } => match op {
+                0 => subpackets.iter().map(|pkt| pkt.value()).sum(),
+                1 => subpackets.iter().map(|pkt| pkt.value()).product(),
+                2 => subpackets.iter().map(|pkt| pkt.value()).min().unwrap(),
+                3 => subpackets.iter().map(|pkt| pkt.value()).max().unwrap(),
+                5 => {
+                    if subpackets[0].value() > subpackets[1].value() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                6 => {
+                    if subpackets[0].value() < subpackets[1].value() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                7 => {
+                    if subpackets[0].value() == subpackets[1].value() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                _ => panic!("unexpected op type: {}", op),
+            },
+        }
+    }
+}
+
+/// Parse a transmission written in hexadecimal, like the puzzle input
+impl FromStr for Packet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Packet::decode(&Day16::parse(s)?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum PacketData {
+    /// A number, most significant nibble first
+    Literal { nibbles: Vec<u8> },
+    /// An operation (sum, product, minimum, maximum, greater than, less than or equal to, by type
+    /// ID) applied to the subpackets
+    Operator { op: u8, subpackets: Vec<Packet> },
+}
+
+impl PacketData {
+    fn literal(input: (&[u8], usize)) -> IResult<(&[u8], usize), PacketData> {
+        use nom::bits::complete::take;
+        let mut input = input;
+        let mut nibbles = Vec::new();
+        loop {
+            let (i, (next, nibble)): (_, (u8, _)) = tuple((take(1usize), take(4usize)))(input)?;
+            input = i;
+            nibbles.push(nibble);
+            if next == 0 {
+                break;
+            }
+        }
+        Ok((input, PacketData::Literal { nibbles }))
+    }
+
+    fn operator(op: u8, input: (&[u8], usize)) -> IResult<(&[u8], usize), PacketData> {
+        use nom::bits::complete::take;
+        let op_start = input;
+        let mut subpackets = Vec::new();
+        let (input, length_type): (_, u8) = take(1usize)(input)?;
+        let mut input = input;
+
+        if length_type == 0 {
+            let start = input;
+            let (i, mut bits): (_, u16) = take(15usize)(input)?;
+            input = i;
+
+            let mut sub: Vec<u8> = Vec::new();
+            while bits >= 8 {
+                let (i, byte) = take(8usize)(input)?;
+                input = i;
+
+                sub.push(byte);
+                bits -= 8;
+            }
+            if bits != 0 {
+                let (i, byte): (_, u8) = take(bits)(input)?;
+                input = i;
+                sub.push(byte << (8 - bits));
+            }
+
+            let mut sub: &[u8] = &sub;
+            let mut offset = 0;
+            while sub.len() > 1 {
+                // The subpackets were copied out, so errors in them can only point at the length
+                let ((s, o), pkt) = Packet::from_transmission((sub, offset))
+                    .map_err(|e| e.map(|_| Error::new(start, ErrorKind::LengthValue)))?;
+                sub = s;
+                offset = o;
+                subpackets.push(pkt);
+            }
+        } else {
+            let (i, num_pkts): (_, u16) = take(11usize)(input)?;
+            input = i;
+
+            for _ in 0..num_pkts {
+                let (i, pkt) = Packet::from_transmission(input)?;
+                input = i;
+                subpackets.push(pkt);
+            }
+        }
+
+        // Comparisons take exactly two operands, and everything else at least one
+        let operands = match op {
+            5..=7 => 2..=2,
+            _ => 1..=usize::MAX,
+        };
+        if !operands.contains(&subpackets.len()) {
+            return Err(nom::Err::Error(Error::new(op_start, ErrorKind::Verify)));
+        }
+
+        Ok((input, PacketData::Operator { op, subpackets }))
+    }
+}
+
+mod parse {
+    use std::num::ParseIntError;
+
+    use super::*;
+
+    use nom::{bytes::complete::take_while_m_n, combinator::map_res, multi::many1, IResult};
+
+    fn from_hex(input: &str) -> Result<u8, ParseIntError> {
+        fn inner(input: &str) -> Result<u8, ParseIntError> {
+            u8::from_str_radix(input, 16)
+        }
+        if input.len() == 1 {
+            let mut input = input.to_owned();
+            input.push('0');
+            inner(&input)
+        } else {
+            inner(input)
+        }
+    }
+
+    pub(super) fn hex_byte(input: &str) -> IResult<&str, u8> {
+        map_res(
+            take_while_m_n(1, 2, |c: char| c.is_ascii_hexdigit()),
+            from_hex,
+        )(input)
+    }
+
+    pub(super) fn input(input: &str) -> IResult<&str, Vec<u8>> {
+        many1(hex_byte)(input)
+    }
+}
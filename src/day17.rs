@@ -0,0 +1,196 @@
+use std::ops::RangeInclusive;
+
+use crate::crosscheck::Reference;
+use crate::parse_error::ParseError;
+use crate::{Params, Result, Solution};
+use anyhow::anyhow;
+use nom::Finish;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 17;
+
+    type Input = TargetArea;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rest, out) = parse::input(input)
+            .finish()
+            .map_err(|e| ParseError::from_nom(input, e))?;
+        ParseError::check_trailing(input, rest)?;
+        Ok(out)
+    }
+
+    fn part_one(input: Self::Input, _params: &Params) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: Self::Input, _params: &Params) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(target: TargetArea) -> Result<i64> {
+    let x_max = *target.x_range.end();
+    let y_min = *target.y_range.start();
+
+    // If some x velocity runs out over the target, the probe can fall straight down through it,
+    // and the fastest it can be going then is fast enough to reach the bottom row in one step
+    let stalls = (0..)
+        .map(|v| v * (v + 1) / 2)
+        .take_while(|&x| x <= x_max)
+        .any(|x| target.x_range.contains(&x));
+    if stalls {
+        let y_start = -y_min - 1;
+        return Ok(y_start * (y_start + 1) / 2);
+    }
+
+    // Otherwise the highest shot is whichever hits with the most upward velocity
+    for y in (y_min..=-y_min).rev() {
+        if (0..=x_max).any(|x| Probe::with_velocity(x, y).will_hit(&target)) {
+            return Ok(if y > 0 { y * (y + 1) / 2 } else { 0 });
+        }
+    }
+    Err(anyhow!("no velocity hits the target"))
+}
+
+fn part_two(target: TargetArea) -> Result<i64> {
+    let x_max = *target.x_range.end();
+    let y_min = *target.y_range.start();
+    let mut count = 0;
+    for x in 0..=x_max {
+        for y in y_min..=-y_min {
+            let probe = Probe::with_velocity(x, y);
+            if probe.will_hit(&target) {
+                count += 1;
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+impl Reference for Day17 {
+    fn reference_part_one(input: Self::Input, _params: &Params) -> Result<Self::Output1> {
+        hits(&input)
+            .into_iter()
+            .max()
+            .ok_or_else(|| anyhow!("no velocity hits the target"))
+    }
+
+    fn reference_part_two(input: Self::Input, _params: &Params) -> Result<Self::Output2> {
+        Ok(hits(&input).len() as i64)
+    }
+}
+
+/// The highest point of every trajectory that hits the target
+///
+/// Every velocity that could possibly hit is simulated step by step: any faster, in any
+/// direction, and the probe is already past the target after its first step.
+fn hits(target: &TargetArea) -> Vec<i64> {
+    let (x_min, x_max) = (*target.x_range.start(), *target.x_range.end());
+    let (y_min, y_max) = (*target.y_range.start(), *target.y_range.end());
+    let y_limit = y_min.abs().max(y_max.abs());
+
+    let mut heights = Vec::new();
+    for x_velocity in x_min.min(0)..=x_max.max(0) {
+        for y_velocity in -y_limit..=y_limit {
+            let (mut x, mut y) = (0, 0);
+            let (mut dx, mut dy) = (x_velocity, y_velocity);
+            let mut top = 0;
+            // Once the probe is below the target and falling, it can't come back up
+            while y >= y_min || dy > 0 {
+                x += dx;
+                y += dy;
+                dx -= dx.signum();
+                dy -= 1;
+                top = top.max(y);
+                if target.x_range.contains(&x) && target.y_range.contains(&y) {
+                    heights.push(top);
+                    break;
+                }
+            }
+        }
+    }
+    heights
+}
+
+#[derive(Clone, Debug)]
+pub struct TargetArea {
+    pub x_range: RangeInclusive<i64>,
+    pub y_range: RangeInclusive<i64>,
+}
+
+#[derive(Clone, Debug)]
+struct Probe {
+    x: i64,
+    y: i64,
+    x_velocity: i64,
+    y_velocity: i64,
+}
+
+impl Probe {
+    fn with_velocity(x_velocity: i64, y_velocity: i64) -> Self {
+        Probe {
+            x: 0,
+            y: 0,
+            x_velocity,
+            y_velocity,
+        }
+    }
+
+    fn step(&mut self) {
+        use std::cmp::Ordering;
+        self.x += self.x_velocity;
+        self.y += self.y_velocity;
+        match Ord::cmp(&self.x_velocity, &0) {
+            Ordering::Greater => {
+                self.x_velocity -= 1;
+            }
+            Ordering::Less => {
+                self.x_velocity += 1;
+            }
+            _ => {}
+        }
+        self.y_velocity -= 1;
+    }
+
+    fn will_hit(self, target: &TargetArea) -> bool {
+        let mut probe = self;
+        while probe.y_velocity >= 0 || probe.y >= *target.y_range.start() {
+            if target.x_range.contains(&probe.x) && target.y_range.contains(&probe.y) {
+                return true;
+            }
+            probe.step();
+        }
+        false
+    }
+}
+
+mod parse {
+    use nom::{bytes::complete::tag, IResult};
+
+    use super::*;
+
+    pub(super) fn input(input: &str) -> IResult<&str, TargetArea> {
+        let (input, _) = tag("target area: x=")(input)?;
+        let (input, x_min) = nom::character::complete::i64(input)?;
+        let (input, _) = tag("..")(input)?;
+        let (input, x_max) = nom::character::complete::i64(input)?;
+        let (input, _) = tag(", y=")(input)?;
+        let (input, y_min) = nom::character::complete::i64(input)?;
+        let (input, _) = tag("..")(input)?;
+        let (input, y_max) = nom::character::complete::i64(input)?;
+
+        Ok((
+            input,
+            TargetArea {
+                x_range: (x_min..=x_max),
+                y_range: (y_min..=y_max),
+            },
+        ))
+    }
+}
@@ -0,0 +1,286 @@
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::parse_error::ParseError;
+use crate::{Params, Result, Solution};
+use nom::Finish;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 18;
+
+    type Input = Vec<SnailfishNumber>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rest, out) = parse::input(input.trim())
+            .finish()
+            .map_err(|e| ParseError::from_nom(input, e))?;
+        ParseError::check_trailing(input, rest)?;
+        Ok(out)
+    }
+
+    fn part_one(input: Self::Input, _params: &Params) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: Self::Input, _params: &Params) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(nums: Vec<SnailfishNumber>) -> Result<u64> {
+    let mut nums = nums.into_iter();
+    let first = nums.next().unwrap();
+    let res = nums.fold(first, Add::add);
+    Ok(res.magnitude())
+}
+
+fn part_two(mut nums: Vec<SnailfishNumber>) -> Result<u64> {
+    let mut max = 0;
+
+    while let Some(a) = nums.pop() {
+        for b in nums.iter().cloned() {
+            let c = (a.clone() + b.clone()).magnitude();
+            if c > max {
+                max = c;
+            }
+            let c = (b + a.clone()).magnitude();
+            if c > max {
+                max = c;
+            }
+        }
+    }
+
+    Ok(max)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Token {
+    Open,
+    Close,
+    Literal(u8),
+    Comma,
+}
+
+/// A snailfish number, stored as the tokens it's written with
+#[derive(Debug, Clone)]
+pub struct SnailfishNumber(Vec<Token>);
+
+impl FromStr for SnailfishNumber {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (rest, num) = parse::snailfish_number(s.trim())
+            .finish()
+            .map_err(|e| ParseError::from_nom(s, e))?;
+        ParseError::check_trailing(s, rest)?;
+        Ok(num)
+    }
+}
+
+impl std::fmt::Display for SnailfishNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for tok in self.0.iter() {
+            match tok {
+                Token::Open => write!(f, "[")?,
+                Token::Close => write!(f, "]")?,
+                Token::Literal(val) => write!(f, "{}", val)?,
+                Token::Comma => write!(f, ",")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Snailfish addition: pair the two numbers up, then reduce the pair
+impl Add for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, right: SnailfishNumber) -> SnailfishNumber {
+        let mut res = self;
+        res.0.insert(0, Token::Open);
+        res.0.push(Token::Comma);
+        res.0.extend(right.0);
+        res.0.push(Token::Close);
+
+        res.reduce()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ReduceState {
+    Done,
+    NotDone,
+}
+
+impl SnailfishNumber {
+    fn reduce_step(self) -> (Self, ReduceState) {
+        let mut depth = 0;
+        for i in 0..self.0.len() {
+            match self.0[i] {
+                Token::Open => {
+                    depth += 1;
+                }
+                Token::Close => {
+                    depth -= 1;
+                }
+                _ => {}
+            }
+            if depth > 4 {
+                return (self.explode(i), ReduceState::NotDone);
+            }
+        }
+
+        for i in 0..self.0.len() {
+            if let Token::Literal(val) = self.0[i] {
+                if val > 9 {
+                    return (self.split(i), ReduceState::NotDone);
+                }
+            }
+        }
+        (self, ReduceState::Done)
+    }
+
+    /// Explode and split until there's nothing left to do
+    pub fn reduce(self) -> Self {
+        let mut num = self;
+        let mut state = ReduceState::NotDone;
+        while state != ReduceState::Done {
+            let (n, s) = num.reduce_step();
+            num = n;
+            state = s;
+        }
+        num
+    }
+
+    fn split(mut self, i: usize) -> Self {
+        let val = if let Token::Literal(val) = self.0[i] {
+            val
+        } else {
+            panic!("invalid split");
+        };
+        let (val, extra) = (val / 2, val % 2);
+        self.0[i] = Token::Close;
+        self.0.insert(i, Token::Literal(val + extra));
+        self.0.insert(i, Token::Comma);
+        self.0.insert(i, Token::Literal(val));
+        self.0.insert(i, Token::Open);
+        self
+    }
+
+    fn explode(mut self, i: usize) -> Self {
+        let (left, right) = match self.0[i..i + 5] {
+            [Token::Open, Token::Literal(left), Token::Comma, Token::Literal(right), Token::Close] => {
+                (left, right)
+            }
+            _ => panic!("invalid explode"),
+        };
+
+        self.0[i..].rotate_left(4);
+        self.0.truncate(self.0.len() - 4);
+        self.0[i] = Token::Literal(0);
+
+        for tok in self.0[..i].iter_mut().rev() {
+            if let Token::Literal(ref mut val) = tok {
+                *val += left;
+                break;
+            }
+        }
+
+        for tok in self.0[i + 1..].iter_mut() {
+            if let Token::Literal(ref mut val) = tok {
+                *val += right;
+                break;
+            }
+        }
+
+        self
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        let mut base = 1;
+        let mut mul = 1;
+        let mut oldbase = Vec::new();
+        let mut acc = 0;
+
+        for tok in self.0.iter().copied() {
+            match tok {
+                Token::Open => {
+                    oldbase.push(base);
+                    base *= mul;
+                    mul = 3;
+                }
+                Token::Close => {
+                    base = oldbase.pop().unwrap();
+                }
+                Token::Comma => {
+                    mul = 2;
+                }
+                Token::Literal(val) => {
+                    acc += (val as u64) * mul * base;
+                }
+            }
+        }
+
+        acc
+    }
+}
+
+mod parse {
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{digit1, line_ending, space0},
+        combinator::{map_res, verify},
+        multi::{many1, separated_list1},
+        sequence::preceded,
+        IResult,
+    };
+
+    use super::*;
+
+    fn token(s: &str) -> IResult<&str, Token> {
+        map_res(
+            alt((tag("["), tag("]"), tag(","), digit1)),
+            |t| -> Result<Token> {
+                Ok(match t {
+                    "[" => Token::Open,
+                    "]" => Token::Close,
+                    "," => Token::Comma,
+                    _ => Token::Literal(t.parse()?),
+                })
+            },
+        )(s)
+    }
+
+    /// Whether the tokens make up exactly one number: a literal, or a bracketed pair of numbers
+    fn well_formed(tokens: &[Token]) -> bool {
+        fn number(tokens: &[Token]) -> Option<&[Token]> {
+            match tokens.split_first()? {
+                (Token::Literal(_), rest) => Some(rest),
+                (Token::Open, rest) => match number(rest)? {
+                    [Token::Comma, rest @ ..] => match number(rest)? {
+                        [Token::Close, rest @ ..] => Some(rest),
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        matches!(number(tokens), Some([]))
+    }
+
+    pub(super) fn snailfish_number(s: &str) -> IResult<&str, SnailfishNumber> {
+        let (s, num) = verify(many1(preceded(space0, token)), |t: &[Token]| well_formed(t))(s)?;
+        Ok((s, SnailfishNumber(num)))
+    }
+
+    pub(super) fn input(s: &str) -> IResult<&str, Vec<SnailfishNumber>> {
+        separated_list1(line_ending, snailfish_number)(s)
+    }
+}
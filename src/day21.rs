@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+
+use crate::crosscheck::Reference;
+use crate::parse_error::ParseError;
+use crate::{Param, Params, Result, Solution};
+use anyhow::ensure;
+use nom::Finish;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 21;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "part1_target",
+            default: "1000",
+            help: "winning score with the deterministic die",
+        },
+        Param {
+            name: "part2_target",
+            default: "21",
+            help: "winning score with the Dirac die",
+        },
+    ];
+
+    type Input = GameState;
+    type Output1 = u32;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rest, out) = parse::input(input)
+            .finish()
+            .map_err(|e| ParseError::from_nom(input, e))?;
+        ParseError::check_trailing(input, rest)?;
+        Ok(out)
+    }
+
+    fn part_one(input: Self::Input, params: &Params) -> Result<Self::Output1> {
+        part_one(input, part1_target(params)?)
+    }
+
+    fn part_two(input: Self::Input, params: &Params) -> Result<Self::Output2> {
+        part_two(input, part2_target(params)?)
+    }
+}
+
+/// The winning score for part one, low enough that the answer fits in a `u32`
+fn part1_target(params: &Params) -> Result<u32> {
+    let target = params.get("part1_target")?;
+    ensure!(target <= 50_000, "part1_target can be at most 50000");
+    Ok(target)
+}
+
+/// The winning score for part two, low enough that the universes can be counted in a `u64`
+fn part2_target(params: &Params) -> Result<u32> {
+    let target = params.get("part2_target")?;
+    ensure!(target <= 27, "part2_target can be at most 27");
+    Ok(target)
+}
+
+fn part_one(mut game: GameState, target: u32) -> Result<u32> {
+    let mut roll = 6;
+    let mut num_rolls = 3;
+
+    loop {
+        game.0.turn(roll);
+        if game.0.score >= target {
+            return Ok(game.1.score * num_rolls);
+        }
+
+        roll = (roll + 9) % 10;
+        num_rolls += 3;
+
+        game.1.turn(roll);
+        if game.1.score >= target {
+            return Ok(game.0.score * num_rolls);
+        }
+
+        roll = (roll + 9) % 10;
+        num_rolls += 3;
+    }
+}
+
+fn part_two(game: GameState, target: u32) -> Result<u64> {
+    let mut games = vec![(0u64, 0u64); 10 * 10 * (target * target) as usize];
+
+    for score1 in (0..target).rev() {
+        for score2 in 0..=score1 {
+            for pos1 in 0..10 {
+                for pos2 in 0..10 {
+                    let p1 = PlayerState {
+                        pos: pos1,
+                        score: score1,
+                    };
+                    let p2 = PlayerState {
+                        pos: pos2,
+                        score: score2,
+                    };
+
+                    let calc = |p1: &PlayerState, p2: &PlayerState, roll, mul| -> (u64, u64) {
+                        let mut p1 = p1.clone();
+                        p1.turn(roll);
+                        let (a, b) = outcome(&GameState(p2.clone(), p1), &games, target);
+                        assert!((a, b) != (0, 0));
+                        (b * mul, a * mul)
+                    };
+
+                    let total = [
+                        calc(&p1, &p2, 3, 1),
+                        calc(&p1, &p2, 4, 3),
+                        calc(&p1, &p2, 5, 6),
+                        calc(&p1, &p2, 6, 7),
+                        calc(&p1, &p2, 7, 6),
+                        calc(&p1, &p2, 8, 3),
+                        calc(&p1, &p2, 9, 1),
+                    ]
+                    .into_iter()
+                    .fold((0, 0), |(x1, y1), (x2, y2)| (x1 + x2, y1 + y2));
+
+                    games[index(&GameState(p1, p2), target)] = total;
+                }
+            }
+        }
+
+        for score2 in 0..=score1 {
+            for pos1 in 0..10 {
+                for pos2 in 0..10 {
+                    let p1 = PlayerState {
+                        pos: pos1,
+                        score: score2,
+                    };
+                    let p2 = PlayerState {
+                        pos: pos2,
+                        score: score1,
+                    };
+
+                    let calc = |p1: &PlayerState, p2: &PlayerState, roll, mul| -> (u64, u64) {
+                        let mut p1 = p1.clone();
+                        p1.turn(roll);
+                        let (a, b) = outcome(&GameState(p2.clone(), p1), &games, target);
+                        assert!((a, b) != (0, 0));
+                        (b * mul, a * mul)
+                    };
+
+                    let total = [
+                        calc(&p1, &p2, 3, 1),
+                        calc(&p1, &p2, 4, 3),
+                        calc(&p1, &p2, 5, 6),
+                        calc(&p1, &p2, 6, 7),
+                        calc(&p1, &p2, 7, 6),
+                        calc(&p1, &p2, 8, 3),
+                        calc(&p1, &p2, 9, 1),
+                    ]
+                    .into_iter()
+                    .fold((0, 0), |(x1, y1), (x2, y2)| (x1 + x2, y1 + y2));
+
+                    games[index(&GameState(p1, p2), target)] = total;
+                }
+            }
+        }
+    }
+
+    let (a, b) = outcome(&game, &games, target);
+    let max = if a > b { a } else { b };
+    Ok(max)
+}
+
+fn outcome(game: &GameState, known: &[(u64, u64)], target: u32) -> (u64, u64) {
+    if game.1.score >= target {
+        (0, 1)
+    } else {
+        known[index(game, target)]
+    }
+}
+
+fn index(game: &GameState, target: u32) -> usize {
+    game.0.pos as usize
+        + 10 * game.1.pos as usize
+        + 100 * game.0.score as usize
+        + 100 * target as usize * game.1.score as usize
+}
+
+impl Reference for Day21 {
+    fn reference_part_one(input: Self::Input, params: &Params) -> Result<Self::Output1> {
+        play_deterministic(input, part1_target(params)?)
+    }
+
+    fn reference_part_two(input: Self::Input, params: &Params) -> Result<Self::Output2> {
+        let target = part2_target(params)?;
+        let pos = [u32::from(input.0.pos), u32::from(input.1.pos)];
+        let wins = count_wins(pos, [0, 0], target, &mut HashMap::new());
+        Ok(wins[0].max(wins[1]))
+    }
+}
+
+/// Play the game out roll by roll with a die that counts from 1 to 100
+fn play_deterministic(game: GameState, target: u32) -> Result<u32> {
+    let mut pos = [u32::from(game.0.pos), u32::from(game.1.pos)];
+    let mut scores = [0; 2];
+    let mut die = 0;
+    let mut rolls = 0;
+    for player in [0, 1].into_iter().cycle() {
+        for _ in 0..3 {
+            die = die % 100 + 1;
+            rolls += 1;
+            pos[player] = (pos[player] + die - 1) % 10 + 1;
+        }
+        scores[player] += pos[player];
+        if scores[player] >= target {
+            return Ok(scores[1 - player] * rolls);
+        }
+    }
+    unreachable!()
+}
+
+/// How many universes each player wins in, counting forwards from the given positions and scores
+///
+/// The player about to move comes first. Each of the 27 ways three Dirac dice can land is played
+/// out separately, with the results for each state remembered.
+fn count_wins(
+    pos: [u32; 2],
+    scores: [u32; 2],
+    target: u32,
+    memo: &mut HashMap<([u32; 2], [u32; 2]), [u64; 2]>,
+) -> [u64; 2] {
+    if let Some(&wins) = memo.get(&(pos, scores)) {
+        return wins;
+    }
+
+    let mut wins = [0, 0];
+    for a in 1..=3 {
+        for b in 1..=3 {
+            for c in 1..=3 {
+                let moved = (pos[0] + a + b + c - 1) % 10 + 1;
+                let score = scores[0] + moved;
+                if score >= target {
+                    wins[0] += 1;
+                } else {
+                    let [theirs, ours] =
+                        count_wins([pos[1], moved], [scores[1], score], target, memo);
+                    wins[0] += ours;
+                    wins[1] += theirs;
+                }
+            }
+        }
+    }
+    memo.insert((pos, scores), wins);
+    wins
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlayerState {
+    /// The square the player is on, modulo 10 (so square 10 is 0)
+    pub pos: u8,
+    pub score: u32,
+}
+
+impl PlayerState {
+    fn turn(&mut self, roll: u8) {
+        self.pos = (self.pos + roll) % 10;
+        self.score += u32::from(match self.pos {
+            0 => 10,
+            _ => self.pos,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameState(pub PlayerState, pub PlayerState);
+
+mod parse {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{digit1, line_ending},
+        sequence::{separated_pair, tuple},
+        IResult,
+    };
+
+    use super::*;
+
+    fn player_state(s: &str) -> IResult<&str, PlayerState> {
+        let (s, _) = tuple((tag("Player "), digit1, tag(" starting position: ")))(s)?;
+        let (s, pos) = nom::character::complete::u8(s)?;
+        // Positions are kept modulo 10, so square 10 is stored as 0
+        Ok((
+            s,
+            PlayerState {
+                pos: pos % 10,
+                score: 0,
+            },
+        ))
+    }
+
+    pub(super) fn input(s: &str) -> IResult<&str, GameState> {
+        let (s, (p1, p2)) = separated_pair(player_state, line_ending, player_state)(s)?;
+
+        Ok((s, GameState(p1, p2)))
+    }
+}
@@ -0,0 +1,181 @@
+use crate::parse_error::ParseError;
+use crate::{Param, Params, Result, Solution};
+use anyhow::ensure;
+use nom::Finish;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 5;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "grid_size",
+        default: "1000",
+        help: "width and height of the ocean floor grid",
+    }];
+
+    type Input = Vec<Line>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rest, lines) = parse::lines(input)
+            .finish()
+            .map_err(|e| ParseError::from_nom(input, e))?;
+        ParseError::check_rest(input, rest, parse::line)?;
+        Ok(lines)
+    }
+
+    fn part_one(input: Self::Input, params: &Params) -> Result<Self::Output1> {
+        part_one(input, params.get("grid_size")?)
+    }
+
+    fn part_two(input: Self::Input, params: &Params) -> Result<Self::Output2> {
+        part_two(input, params.get("grid_size")?)
+    }
+}
+
+/// An empty `size` by `size` grid, as long as every line fits in it
+fn grid(lines: &[Line], size: usize) -> Result<Vec<u8>> {
+    // Points are 16-bit, so a bigger grid would only be empty space
+    ensure!(size <= 1 << 16, "grid_size can be at most {}", 1 << 16);
+    let fits = |p: &Point| (p.x as usize) < size && (p.y as usize) < size;
+    ensure!(
+        lines.iter().all(|l| fits(&l.start) && fits(&l.end)),
+        "a line is outside the {}x{} grid",
+        size,
+        size
+    );
+    Ok(vec![0u8; size * size])
+}
+
+fn part_one(lines: Vec<Line>, size: usize) -> Result<usize> {
+    let mut grid = grid(&lines, size)?;
+
+    for l in lines.into_iter() {
+        if l.start.x == l.end.x {
+            let x = l.start.x;
+            let (s, e) = in_order(l.start.y, l.end.y);
+            for y in s..=e {
+                let idx = (x as usize) * size + (y as usize);
+                grid[idx] += 1;
+            }
+        }
+
+        if l.start.y == l.end.y {
+            let y = l.start.y;
+            let (s, e) = in_order(l.start.x, l.end.x);
+            for x in s..=e {
+                let idx = (x as usize) * size + (y as usize);
+                grid[idx] += 1;
+            }
+        }
+    }
+
+    Ok(grid.into_iter().filter(|c| *c > 1).count())
+}
+
+fn part_two(lines: Vec<Line>, size: usize) -> Result<usize> {
+    let mut grid = grid(&lines, size)?;
+
+    for l in lines.into_iter() {
+        if l.start.x == l.end.x {
+            let x = l.start.x;
+            let (s, e) = in_order(l.start.y, l.end.y);
+            for y in s..=e {
+                let idx = (x as usize) * size + (y as usize);
+                grid[idx] += 1;
+            }
+            continue;
+        }
+
+        if l.start.y == l.end.y {
+            let y = l.start.y;
+            let (s, e) = in_order(l.start.x, l.end.x);
+            for x in s..=e {
+                let idx = (x as usize) * size + (y as usize);
+                grid[idx] += 1;
+            }
+            continue;
+        }
+
+        if diff(l.start.x, l.end.x) == diff(l.start.y, l.end.y) {
+            let diff = diff(l.start.x, l.end.x);
+            for d in 0..=diff {
+                let x = if l.end.x > l.start.x {
+                    l.start.x + d
+                } else {
+                    l.start.x - d
+                };
+                let y = if l.end.y > l.start.y {
+                    l.start.y + d
+                } else {
+                    l.start.y - d
+                };
+
+                let idx = (x as usize) * size + (y as usize);
+                grid[idx] += 1;
+            }
+        }
+    }
+
+    Ok(grid.into_iter().filter(|c| *c > 1).count())
+}
+
+fn in_order(a: u16, b: u16) -> (u16, u16) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn diff(a: u16, b: u16) -> u16 {
+    a.abs_diff(b)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Point {
+    pub x: u16,
+    pub y: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+mod parse {
+    use super::*;
+
+    use nom::branch::alt;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::line_ending;
+    use nom::combinator::eof;
+    use nom::multi::many1;
+    use nom::sequence::{separated_pair, terminated};
+    use nom::IResult;
+
+    fn point(s: &str) -> IResult<&str, Point> {
+        let (s, (x, y)) = separated_pair(
+            nom::character::complete::u16,
+            tag(","),
+            nom::character::complete::u16,
+        )(s)?;
+        Ok((s, Point { x, y }))
+    }
+
+    pub(super) fn line(s: &str) -> IResult<&str, Line> {
+        // The last line's newline is optional, for `--trailing-newline none`
+        let (s, (start, end)) = terminated(
+            separated_pair(point, tag(" -> "), point),
+            alt((line_ending, eof)),
+        )(s)?;
+        Ok((s, Line { start, end }))
+    }
+
+    pub(super) fn lines(s: &str) -> IResult<&str, Vec<Line>> {
+        many1(line)(s)
+    }
+}
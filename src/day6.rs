@@ -0,0 +1,101 @@
+use crate::crosscheck::Reference;
+use crate::parse_error::ParseError;
+use crate::{Param, Params, Result, Solution};
+use anyhow::{anyhow, ensure};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 6;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "part1_days",
+            default: "80",
+            help: "days to simulate in part one",
+        },
+        Param {
+            name: "part2_days",
+            default: "256",
+            help: "days to simulate in part two",
+        },
+    ];
+
+    type Input = Vec<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let timer = |s: &str| {
+            let s = s.trim();
+            match s.parse::<u8>() {
+                Ok(t) if t <= 8 => Ok(t),
+                _ => Err(ParseError::at(input, s, "expected a timer from 0 to 8")),
+            }
+        };
+        Ok(input.split(',').map(timer).collect::<Result<_, _>>()?)
+    }
+
+    fn part_one(input: Self::Input, params: &Params) -> Result<Self::Output1> {
+        simulate(input, params.get("part1_days")?)
+    }
+
+    fn part_two(input: Self::Input, params: &Params) -> Result<Self::Output2> {
+        simulate(input, params.get("part2_days")?)
+    }
+}
+
+fn simulate(start: Vec<u8>, days: usize) -> Result<usize> {
+    let mut counts = [0usize; 9];
+
+    for fish in start.into_iter() {
+        counts[fish as usize] += 1;
+    }
+
+    let too_many = || anyhow!("too many fish to count after {} days", days);
+    for _ in 0..days {
+        let new_fish = counts[0];
+        counts.rotate_left(1);
+        counts[6] = counts[6].checked_add(new_fish).ok_or_else(too_many)?;
+    }
+
+    counts
+        .into_iter()
+        .try_fold(0usize, usize::checked_add)
+        .ok_or_else(too_many)
+}
+
+impl Reference for Day6 {
+    fn reference_part_one(input: Self::Input, params: &Params) -> Result<Self::Output1> {
+        simulate_each(input, params.get("part1_days")?)
+    }
+
+    fn reference_part_two(input: Self::Input, params: &Params) -> Result<Self::Output2> {
+        simulate_each(input, params.get("part2_days")?)
+    }
+}
+
+/// More fish than this won't fit comfortably in memory
+const MAX_FISH: usize = 50_000_000;
+
+/// Simulate every fish individually
+fn simulate_each(mut fish: Vec<u8>, days: usize) -> Result<usize> {
+    for _ in 0..days {
+        let mut born = 0;
+        for timer in fish.iter_mut() {
+            if *timer == 0 {
+                *timer = 6;
+                born += 1;
+            } else {
+                *timer -= 1;
+            }
+        }
+        fish.resize(fish.len() + born, 8);
+        ensure!(
+            fish.len() <= MAX_FISH,
+            "more than {} fish, too many to simulate one by one",
+            MAX_FISH
+        );
+    }
+    Ok(fish.len())
+}
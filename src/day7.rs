@@ -0,0 +1,56 @@
+use crate::{Params, Result, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 7;
+
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let v: Result<Vec<usize>, _> = input
+            .split(',')
+            .map(|s| s.trim().parse::<usize>())
+            .collect();
+        Ok(v?)
+    }
+
+    fn part_one(input: Self::Input, _params: &Params) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: Self::Input, _params: &Params) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn calc_min<Map: Clone + Fn(usize) -> usize>(start: Vec<usize>, cost_map: Map) -> Result<usize> {
+    let max = start.iter().cloned().max().unwrap();
+
+    // Start with the cost to send them all to zero.
+    let mut min: usize = start.iter().cloned().map(cost_map.clone()).sum();
+
+    for pos in 1..=max {
+        let cost: usize = start.iter().cloned().map(|s| cost_map(diff(s, pos))).sum();
+        if cost < min {
+            min = cost;
+        }
+    }
+
+    Ok(min)
+}
+
+fn part_one(start: Vec<usize>) -> Result<usize> {
+    calc_min(start, |d| d)
+}
+
+fn part_two(start: Vec<usize>) -> Result<usize> {
+    calc_min(start, |d| d * (d + 1) / 2)
+}
+
+fn diff(a: usize, b: usize) -> usize {
+    b.abs_diff(a)
+}
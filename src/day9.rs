@@ -0,0 +1,209 @@
+use anyhow::ensure;
+
+use crate::parse_error::ParseError;
+use crate::{Params, Result, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u64 = 2021;
+    const DAY: u64 = 9;
+
+    type Input = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines: Vec<_> = input.lines().collect();
+        let width = lines.first().map_or(1, |l| l.len());
+        ensure!(
+            lines.iter().all(|l| l.len() == width),
+            "every row must be {} points wide",
+            width
+        );
+
+        let mut grid = Vec::with_capacity(width * lines.len());
+        for line in &lines {
+            grid.extend(ParseError::digits(input, line)?);
+        }
+        Grid::try_with_width(width, grid)
+    }
+
+    fn part_one(input: Self::Input, _params: &Params) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: Self::Input, _params: &Params) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(grid: Grid<u8>) -> Result<usize> {
+    let mut count = 0;
+    for col in 0..grid.width() {
+        for row in 0..grid.height() {
+            let val = grid.get(row, col).unwrap();
+
+            if grid.neighbors(row, col).find(|v| *v <= val).is_none() {
+                count += (1 + val) as usize;
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+fn part_two(mut grid: Grid<u8>) -> Result<usize> {
+    let mut top = [0usize; 3];
+
+    for row in 0..grid.height() {
+        for col in 0..grid.width() {
+            if grid.get(row, col).unwrap_or(9) < 9 {
+                let size = fill(&mut grid, row, col);
+
+                if size > top[0] {
+                    top[0] = size;
+                    top.sort_unstable();
+                }
+            }
+        }
+    }
+
+    Ok(top.into_iter().product())
+}
+
+/// Fill the basin containing the given point with 9s, returning its size
+fn fill(grid: &mut Grid<u8>, row: usize, col: usize) -> usize {
+    let mut size = 0;
+    let mut points = vec![(row, col)];
+    if let Some(cell) = grid.get_mut(row, col) {
+        *cell = 9;
+    }
+
+    while let Some((r, c)) = points.pop() {
+        size += 1;
+
+        for (nr, nc) in AdjacentPoints::new(r, c) {
+            if let Some(cell) = grid.get_mut(nr, nc) {
+                if *cell < 9 {
+                    *cell = 9;
+                    points.push((nr, nc));
+                }
+            }
+        }
+    }
+    size
+}
+
+/// A rectangular grid, stored row by row
+#[derive(Debug, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    grid: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Split `grid` into rows of `width` cells
+    ///
+    /// Panics if `width` is zero or doesn't divide the number of cells.
+    pub fn with_width(width: usize, grid: Vec<T>) -> Self {
+        Self::try_with_width(width, grid).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like [`with_width`](Self::with_width), failing instead of panicking
+    pub fn try_with_width(width: usize, grid: Vec<T>) -> Result<Self> {
+        ensure!(width != 0, "a grid can't be zero cells wide");
+        ensure!(
+            grid.len().is_multiple_of(width),
+            "{} cells don't make rows of {}",
+            grid.len(),
+            width
+        );
+
+        Ok(Grid { width, grid })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.len() / self.width
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if col < self.width && row < self.height() {
+            Some(&mut self.grid[row * self.width + col])
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if col < self.width && row < self.height() {
+            Some(self.grid[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    /// The values of the up to four cells next to the given one
+    pub fn neighbors(&self, row: usize, col: usize) -> Neighbors<'_, T> {
+        Neighbors {
+            points: AdjacentPoints::new(row, col),
+            grid: self,
+        }
+    }
+}
+
+struct AdjacentPoints(std::vec::IntoIter<(usize, usize)>);
+
+impl AdjacentPoints {
+    fn new(row: usize, col: usize) -> Self {
+        let mut points: Vec<(usize, usize)> = Vec::new();
+
+        if let Some(r) = row.checked_sub(1) {
+            points.push((r, col));
+        }
+        if let Some(r) = row.checked_add(1) {
+            points.push((r, col));
+        }
+        if let Some(c) = col.checked_sub(1) {
+            points.push((row, c));
+        }
+        if let Some(c) = col.checked_add(1) {
+            points.push((row, c));
+        }
+
+        AdjacentPoints(points.into_iter())
+    }
+}
+
+impl Iterator for AdjacentPoints {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// An iterator over the values next to a cell, from [`Grid::neighbors`]
+pub struct Neighbors<'a, T> {
+    points: AdjacentPoints,
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T: Copy> Iterator for Neighbors<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (row, col) in self.points.by_ref() {
+            if let Some(val) = self.grid.get(row, col) {
+                return Some(val);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,129 @@
+//! Error categories and process exit codes
+//!
+//! Most errors are plain [`anyhow::Error`]s, but the ones wrapper scripts care about are tagged
+//! with an [`Error`] somewhere in their chain so they get their own exit code:
+//!
+//! | Code | Meaning                                             |
+//! |------|-----------------------------------------------------|
+//! | 0    | Success                                             |
+//! | 1    | Anything else (bad arguments, failed checks, ...)   |
+//! | 2    | The input file doesn't exist                        |
+//! | 3    | The input file couldn't be read                     |
+//! | 4    | The input couldn't be parsed                        |
+//! | 5    | The solution failed on a well-formed input          |
+//! | 6    | The solution ran out of time (see `--timeout`)      |
+//! | 101  | The solution panicked                               |
+//!
+//! When several inputs are solved at once, the code is that of the first one to fail.
+
+use std::fmt::{self, Display};
+use std::io;
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum Error {
+    InputNotFound {
+        path: String,
+        /// Why it couldn't be downloaded instead, if that was tried
+        reason: Option<String>,
+    },
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    Parse(anyhow::Error),
+    Solve {
+        part: u8,
+        source: anyhow::Error,
+    },
+    /// A part was abandoned after running for longer than its time budget
+    Timeout {
+        part: u8,
+        budget: Duration,
+    },
+    /// Some of several inputs failed, and have already been reported
+    InputsFailed {
+        failed: usize,
+        exit_code: u8,
+    },
+}
+
+impl Error {
+    /// An error from reading the input at `path`
+    pub fn read(path: &str, source: io::Error) -> Self {
+        if source.kind() == io::ErrorKind::NotFound {
+            Error::InputNotFound {
+                path: path.to_owned(),
+                reason: None,
+            }
+        } else {
+            Error::Io {
+                path: path.to_owned(),
+                source,
+            }
+        }
+    }
+
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::InputNotFound { .. } => 2,
+            Error::Io { .. } => 3,
+            Error::Parse(_) => 4,
+            Error::Solve { .. } => 5,
+            Error::Timeout { .. } => 6,
+            Error::InputsFailed { exit_code, .. } => *exit_code,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InputNotFound { path, reason } => {
+                write!(f, "no input file found at {}", path)?;
+                if let Some(reason) = reason {
+                    write!(f, " ({})", reason)?;
+                }
+                Ok(())
+            }
+            Error::Io { path, .. } => write!(f, "failed to read {}", path),
+            Error::Parse(_) => write!(f, "failed to parse input"),
+            Error::Solve { part, .. } => write!(f, "part {} failed", part),
+            Error::Timeout { part, budget } => {
+                write!(f, "part {} timed out after {:.2?}", part, budget)
+            }
+            Error::InputsFailed { failed, .. } => write!(f, "{} input(s) failed", failed),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InputNotFound { .. } | Error::Timeout { .. } | Error::InputsFailed { .. } => {
+                None
+            }
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(source) | Error::Solve { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+/// The exit code for an error: that of the first [`Error`] in its chain, or 1
+pub fn exit_code_of(err: &anyhow::Error) -> u8 {
+    err.chain()
+        .find_map(|e| e.downcast_ref::<Error>())
+        .map_or(1, Error::exit_code)
+}
+
+/// Print the error, if any, and turn the result into an exit code
+pub fn exit_code(result: crate::Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            ExitCode::from(exit_code_of(&e))
+        }
+    }
+}
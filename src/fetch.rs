@@ -0,0 +1,127 @@
+//! Downloading puzzle inputs from the Advent of Code website
+//!
+//! Inputs are personal, so fetching needs the `session` cookie from a logged-in browser. It's read
+//! from the `AOC_SESSION` environment variable, or failing that from `~/.config/aoc/session` (or
+//! `$XDG_CONFIG_HOME/aoc/session`). Downloaded inputs are cached under `input/` and never fetched
+//! twice.
+//!
+//! The server can be overridden with `AOC_BASE_URL`, which is mostly useful for testing.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+
+use crate::Result;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/kylewillmon/advent-of-code-2021";
+
+fn config_dir() -> Option<PathBuf> {
+    match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => env::var_os("HOME").map(|home| Path::new(&home).join(".config")),
+    }
+}
+
+/// Find the session token in the environment or config file
+pub fn session_token() -> Result<Option<String>> {
+    if let Ok(token) = env::var("AOC_SESSION") {
+        if !token.trim().is_empty() {
+            return Ok(Some(token.trim().to_owned()));
+        }
+    }
+
+    let path = match config_dir() {
+        Some(dir) => dir.join("aoc").join("session"),
+        None => return Ok(None),
+    };
+    if !path.exists() {
+        return Ok(None);
+    }
+    let token = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read session token from {}", path.display()))?;
+    let token = token.trim();
+    Ok(if token.is_empty() {
+        None
+    } else {
+        Some(token.to_owned())
+    })
+}
+
+/// Client for a (possibly fake) Advent of Code server
+#[derive(Clone, Debug)]
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new<B: Into<String>, S: Into<String>>(base_url: B, session: S) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_owned();
+        Client {
+            base_url,
+            session: session.into(),
+        }
+    }
+
+    /// Create a client from the environment, or `None` if there's no session token
+    pub fn from_env() -> Result<Option<Self>> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        Ok(session_token()?.map(|session| Client::new(base_url, session)))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        ureq::request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+    }
+
+    fn get(&self, url: &str) -> Result<String> {
+        Self::body(url, self.request("GET", url).call())
+    }
+
+    pub(crate) fn post_form(&self, url: &str, form: &[(&str, &str)]) -> Result<String> {
+        Self::body(url, self.request("POST", url).send_form(form))
+    }
+
+    fn body(url: &str, resp: Result<ureq::Response, ureq::Error>) -> Result<String> {
+        match resp {
+            Ok(resp) => Ok(resp.into_string()?),
+            Err(ureq::Error::Status(code, resp)) => {
+                let body = resp.into_string().unwrap_or_default();
+                bail!("{} returned {}: {}", url, code, body.trim())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Download the input for a day
+    pub fn download_input(&self, year: u64, day: u64) -> Result<String> {
+        self.get(&format!("{}/{}/day/{}/input", self.base_url, year, day))
+    }
+
+    /// Return the cached input at `path`, downloading it first if it isn't there
+    pub fn cached_input<P: AsRef<Path>>(&self, year: u64, day: u64, path: P) -> Result<String> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Ok(fs::read_to_string(path)?);
+        }
+
+        let input = self.download_input(year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write to a temporary file first so a failed write never leaves a partial input behind
+        let tmp = path.with_extension("txt.part");
+        fs::write(&tmp, &input)?;
+        fs::rename(&tmp, path)?;
+        Ok(input)
+    }
+}
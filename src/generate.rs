@@ -0,0 +1,804 @@
+//! Random puzzle inputs for stress tests and performance work
+//!
+//! Each generator turns a seed and a size into an input with the same structure as the real one,
+//! so the same seed always gives the same input. The random numbers come from [`Rng`], a small
+//! splitmix64 generator kept here rather than taken from a crate, so that seeds stay reproducible
+//! whatever happens to our dependencies.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use anyhow::{anyhow, bail};
+
+use crate::day11::{self, Day11};
+use crate::{day_label, parse_day_label, Result, Solution};
+
+/// A seeded splitmix64 pseudo-random number generator
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+
+    /// A number in `lo..=hi`
+    pub fn range(&mut self, lo: usize, hi: usize) -> usize {
+        lo + self.below(hi - lo + 1)
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Day 1: `count` sonar depths, wandering downwards like the real ones
+pub fn depths(rng: &mut Rng, count: usize) -> String {
+    let mut depth = rng.range(100, 200);
+    let mut out = String::new();
+    for _ in 0..count {
+        writeln!(out, "{}", depth).unwrap();
+        depth = (depth + rng.range(0, 30)).saturating_sub(10);
+    }
+    out
+}
+
+/// Day 2: `count` submarine commands
+pub fn commands(rng: &mut Rng, count: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..count {
+        let direction = ["forward", "down", "up"][rng.below(3)];
+        writeln!(out, "{} {}", direction, rng.range(1, 9)).unwrap();
+    }
+    out
+}
+
+/// Fill `out` with `count` distinct numbers of `width` bits that start with `prefix`
+///
+/// Any two numbers that share a prefix carry on with both a 0 and a 1 after it, so that however
+/// far the ratings in day 3 are narrowed down, both bits are always still there to choose from.
+fn bit_criteria(rng: &mut Rng, prefix: &mut String, width: usize, count: usize, out: &mut String) {
+    let rest = width - prefix.len();
+    if count == 1 {
+        let start = prefix.len();
+        for _ in 0..rest {
+            prefix.push(if rng.below(2) == 0 { '0' } else { '1' });
+        }
+        writeln!(out, "{}", prefix).unwrap();
+        prefix.truncate(start);
+        return;
+    }
+
+    // Each half can hold at most as many numbers as there are with one more bit fixed
+    let half = 1usize.checked_shl(rest as u32 - 1).unwrap_or(usize::MAX);
+    let zeros = rng.range(count.saturating_sub(half).max(1), (count - 1).min(half));
+    for (bit, count) in [('0', zeros), ('1', count - zeros)] {
+        prefix.push(bit);
+        bit_criteria(rng, prefix, width, count, out);
+        prefix.pop();
+    }
+}
+
+/// Day 3: `count` diagnostic report lines, 12 bits wide like the real ones or wider if needed
+///
+/// The numbers are all different, and always leave a single rating for both parts of the life
+/// support rating to find; see [`bit_criteria`].
+pub fn diagnostics(rng: &mut Rng, count: usize) -> String {
+    assert!(count >= 1, "need at least 1 number");
+    let width = (usize::BITS - (count - 1).leading_zeros()).max(12) as usize;
+    let mut out = String::new();
+    bit_criteria(rng, &mut String::new(), width, count, &mut out);
+
+    // Shuffle the lines, which come out in order
+    let mut lines: Vec<_> = out.lines().collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
+
+/// Day 4: a draw of `numbers` distinct numbers (at most 256), then `cards` bingo cards
+///
+/// Every number is drawn, so every card eventually wins.
+pub fn bingo(rng: &mut Rng, numbers: usize, cards: usize) -> String {
+    assert!((25..=256).contains(&numbers), "need 25 to 256 numbers");
+
+    let mut pool: Vec<_> = (0..numbers).collect();
+    rng.shuffle(&mut pool);
+    let draws: Vec<_> = pool.iter().map(usize::to_string).collect();
+    let mut out = draws.join(",");
+    out.push('\n');
+
+    for _ in 0..cards {
+        rng.shuffle(&mut pool);
+        out.push('\n');
+        for row in pool[..25].chunks(5) {
+            let row: Vec<_> = row.iter().map(|n| format!("{:>2}", n)).collect();
+            writeln!(out, "{}", row.join(" ")).unwrap();
+        }
+    }
+    out
+}
+
+/// Day 5: `lines` horizontal, vertical and diagonal vent lines within a `size` by `size` grid
+pub fn vents(rng: &mut Rng, lines: usize, size: usize) -> String {
+    assert!(size >= 2, "the grid must be at least 2 wide");
+
+    let mut out = String::new();
+    let mut written = 0;
+    while written < lines {
+        let (x1, y1) = (rng.below(size), rng.below(size));
+        let (x2, y2) = match rng.below(3) {
+            0 => (rng.below(size), y1),
+            1 => (x1, rng.below(size)),
+            _ => {
+                let right = rng.below(2) == 0;
+                let down = rng.below(2) == 0;
+                let room_x = if right { size - 1 - x1 } else { x1 };
+                let room_y = if down { size - 1 - y1 } else { y1 };
+                let len = rng.range(0, room_x.min(room_y));
+                (
+                    if right { x1 + len } else { x1 - len },
+                    if down { y1 + len } else { y1 - len },
+                )
+            }
+        };
+        if (x1, y1) != (x2, y2) {
+            writeln!(out, "{},{} -> {},{}", x1, y1, x2, y2).unwrap();
+            written += 1;
+        }
+    }
+    out
+}
+
+/// Days 11 and 15: a `width` by `height` grid of digits from `lo` to `hi`
+pub fn digits(rng: &mut Rng, width: usize, height: usize, lo: u8, hi: u8) -> String {
+    let mut out = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        for _ in 0..width {
+            out.push(char::from(
+                b'0' + lo + rng.below(usize::from(hi - lo) + 1) as u8,
+            ));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Day 11: a `size` by `size` grid of octopuses that sooner or later all flash at once
+///
+/// Fully random grids rarely synchronise once they're bigger than the real 10 by 10, but grids
+/// that start with low energy levels nearly always do, after 50 to 250 steps. The odd grid that
+/// never does is thrown away and another one drawn.
+pub fn octopuses(rng: &mut Rng, size: usize) -> String {
+    assert!(size >= 1, "the grid must be at least 1 wide");
+    loop {
+        let grid = digits(rng, size, size, 0, 6);
+        let cavern = Day11::parse(&grid).expect("generated grids are valid");
+        if day11::first_synchronized_step(cavern).is_some() {
+            return grid;
+        }
+    }
+}
+
+/// Day 6: `count` lanternfish timers between 1 and 5, like the real input
+pub fn lanternfish(rng: &mut Rng, count: usize) -> String {
+    let timers: Vec<_> = (0..count).map(|_| rng.range(1, 5).to_string()).collect();
+    timers.join(",") + "\n"
+}
+
+/// Day 7: `count` crab positions, up to 2000 like the real ones
+pub fn crabs(rng: &mut Rng, count: usize) -> String {
+    let positions: Vec<_> = (0..count).map(|_| rng.below(2000).to_string()).collect();
+    positions.join(",") + "\n"
+}
+
+/// The segments lit up for each digit on a seven-segment display, wired correctly
+const SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Day 8: `count` notes, each with its own random wiring
+pub fn displays(rng: &mut Rng, count: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..count {
+        let mut wires: Vec<_> = "abcdefg".chars().collect();
+        rng.shuffle(&mut wires);
+        let pattern = |rng: &mut Rng, digit: usize| {
+            let mut lit: Vec<_> = SEGMENTS[digit]
+                .bytes()
+                .map(|s| wires[usize::from(s - b'a')])
+                .collect();
+            rng.shuffle(&mut lit);
+            lit.into_iter().collect::<String>()
+        };
+
+        let mut digits: Vec<_> = (0..10).collect();
+        rng.shuffle(&mut digits);
+        let patterns: Vec<_> = digits.iter().map(|&d| pattern(rng, d)).collect();
+        let output: Vec<_> = (0..4)
+            .map(|_| {
+                let digit = rng.below(10);
+                pattern(rng, digit)
+            })
+            .collect();
+        writeln!(out, "{} | {}", patterns.join(" "), output.join(" ")).unwrap();
+    }
+    out
+}
+
+/// Day 9: a `width` by `height` height map
+///
+/// Nearly half the points are 9s, which is enough to split the map into many small basins like a
+/// real one, rather than a few that cover most of the map.
+pub fn height_map(rng: &mut Rng, width: usize, height: usize) -> String {
+    let mut out = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        for _ in 0..width {
+            let digit = if rng.below(20) < 9 { 9 } else { rng.below(9) };
+            out.push(char::from(b'0' + digit as u8));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Day 10: `count` lines of chunks, each either incomplete or corrupted
+///
+/// The first line is always incomplete, since part two needs at least one.
+pub fn chunks(rng: &mut Rng, count: usize) -> String {
+    const OPEN: [char; 4] = ['(', '[', '{', '<'];
+    const CLOSE: [char; 4] = [')', ']', '}', '>'];
+
+    let mut out = String::new();
+    for line in 0..count {
+        let mut open = Vec::new();
+        for _ in 0..rng.range(20, 110) {
+            if open.is_empty() || rng.below(2) == 0 {
+                let kind = rng.below(4);
+                out.push(OPEN[kind]);
+                open.push(kind);
+            } else {
+                out.push(CLOSE[open.pop().unwrap()]);
+            }
+        }
+
+        if open.is_empty() {
+            let kind = rng.below(4);
+            out.push(OPEN[kind]);
+            open.push(kind);
+        }
+        if line > 0 && rng.below(2) == 0 {
+            // Close the innermost chunk with the wrong character
+            let wrong = (open.last().unwrap() + rng.range(1, 3)) % 4;
+            out.push(CLOSE[wrong]);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Day 14: a template of `length` elements, with a rule for every pair of `elements` elements
+pub fn polymer(rng: &mut Rng, length: usize, elements: usize) -> String {
+    assert!((1..=26).contains(&elements), "need 1 to 26 elements");
+    let element = |rng: &mut Rng| char::from(b'A' + rng.below(elements) as u8);
+
+    let mut out: String = (0..length).map(|_| element(rng)).collect();
+    out.push_str("\n\n");
+    for a in 0..elements {
+        for b in 0..elements {
+            let (a, b) = (char::from(b'A' + a as u8), char::from(b'A' + b as u8));
+            writeln!(out, "{}{} -> {}", a, b, element(rng)).unwrap();
+        }
+    }
+    out
+}
+
+/// Where day 13's paper is folded along x and y, in the order the folds are made
+///
+/// These are the folds of the real inputs, which leave a picture 40 dots wide and 6 high.
+const FOLDS: [(char, u32); 12] = [
+    ('x', 655),
+    ('y', 447),
+    ('x', 327),
+    ('y', 223),
+    ('x', 163),
+    ('y', 111),
+    ('x', 81),
+    ('y', 55),
+    ('x', 40),
+    ('y', 27),
+    ('y', 13),
+    ('y', 6),
+];
+
+/// A random coordinate along `axis` that never lands on a fold line as the paper is folded
+fn unfolded(rng: &mut Rng, axis: char) -> u32 {
+    let lines: Vec<_> = FOLDS
+        .iter()
+        .filter(|(a, _)| *a == axis)
+        .map(|(_, at)| *at)
+        .collect();
+    loop {
+        let start = rng.below(2 * lines[0] as usize + 1) as u32;
+        let mut at = start;
+        let folds_cleanly = lines.iter().all(|&line| {
+            if at > line {
+                at = 2 * line - at;
+            }
+            at != line
+        });
+        if folds_cleanly {
+            return start;
+        }
+    }
+}
+
+/// Day 13: `count` dots on transparent paper, followed by the usual folds
+///
+/// The dots are scattered at random, so the folded paper is a picture of noise rather than
+/// letters.
+pub fn transparent_paper(rng: &mut Rng, count: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..count {
+        let x = unfolded(rng, 'x');
+        writeln!(out, "{},{}", x, unfolded(rng, 'y')).unwrap();
+    }
+    out.push('\n');
+    for (axis, at) in FOLDS {
+        writeln!(out, "fold along {}={}", axis, at).unwrap();
+    }
+    out
+}
+
+/// Day 17: a target area below and to the right of the launcher, up to `size` away
+///
+/// Unlike the real inputs, the target often doesn't line up with a point where some x velocity
+/// runs out, which is the case the shortcut for part one has to handle separately.
+pub fn target_area(rng: &mut Rng, size: usize) -> String {
+    assert!(size >= 2, "the target must be at least 2 away");
+    let size = size as i64;
+    let x_min = rng.range(1, size as usize) as i64;
+    let x_max = x_min + rng.range(0, (size / 4) as usize) as i64;
+    let y_min = -(rng.range(2, size as usize) as i64);
+    let y_max = (y_min + rng.range(0, (size / 4) as usize) as i64).min(-1);
+    format!(
+        "target area: x={}..{}, y={}..{}\n",
+        x_min, x_max, y_min, y_max
+    )
+}
+
+/// Day 21: two random starting positions
+pub fn dirac_dice(rng: &mut Rng) -> String {
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.range(1, 10),
+        rng.range(1, 10)
+    )
+}
+
+/// Name the `i`th cave: `aa`, `ab`, ... `zz`, `baa`, ...
+fn cave_name(mut i: usize, upper: bool) -> String {
+    let base = if upper { b'A' } else { b'a' };
+    let mut name = Vec::new();
+    while i > 0 || name.len() < 2 {
+        name.push(base + (i % 26) as u8);
+        i /= 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+/// Day 12: a cave system with `small` small caves, `big` big caves and up to `edges` tunnels
+///
+/// No two big caves are ever connected, since that would allow infinitely many paths. The number
+/// of paths grows very quickly with the number of tunnels.
+pub fn caves(rng: &mut Rng, small: usize, big: usize, edges: usize) -> String {
+    let mut names = vec![String::from("start"), String::from("end")];
+    names.extend(
+        (0..)
+            .map(|i| cave_name(i, false))
+            .filter(|n| n != "start" && n != "end")
+            .take(small),
+    );
+    names.extend((0..big).map(|i| cave_name(i, true)));
+    let is_big = |i: usize| i >= 2 + small;
+
+    let mut seen = HashSet::new();
+    let mut out = String::new();
+    let mut connect = |a: usize, b: usize, out: &mut String| {
+        if a == b || (is_big(a) && is_big(b)) || !seen.insert((a.min(b), a.max(b))) {
+            return false;
+        }
+        writeln!(out, "{}-{}", names[a], names[b]).unwrap();
+        true
+    };
+
+    // Make sure both ends have at least one tunnel
+    let mut written = 0;
+    if names.len() > 2 {
+        for end in [0, 1] {
+            if connect(end, rng.range(2, names.len() - 1), &mut out) {
+                written += 1;
+            }
+        }
+    }
+    // Give up eventually, in case there aren't that many distinct tunnels to be had
+    for _ in 0..edges * 20 {
+        if written >= edges {
+            break;
+        }
+        let (a, b) = (rng.below(names.len()), rng.below(names.len()));
+        if connect(a, b, &mut out) {
+            written += 1;
+        }
+    }
+    out
+}
+
+/// Keep day 16 expression values small enough that no sum or product overflows a `u64`
+const MAX_PACKET_VALUE: u64 = 1 << 40;
+
+fn push_bits(bits: &mut Vec<bool>, value: u64, width: u32) {
+    for i in (0..width).rev() {
+        bits.push((value >> i) & 1 == 1);
+    }
+}
+
+/// Encode a random packet made of `packets` packets in total, returning its bits and value
+fn packet(rng: &mut Rng, packets: usize) -> (Vec<bool>, u64) {
+    let mut bits = Vec::new();
+    push_bits(&mut bits, rng.below(8) as u64, 3);
+
+    if packets <= 1 {
+        let groups = rng.range(1, 4) as u32;
+        let value = rng.below(1 << (4 * groups)) as u64;
+        push_bits(&mut bits, 4, 3);
+        for g in (0..groups).rev() {
+            push_bits(&mut bits, u64::from(g > 0), 1);
+            push_bits(&mut bits, (value >> (4 * g)) & 0xf, 4);
+        }
+        return (bits, value);
+    }
+
+    // Comparisons need exactly two operands; split what's left evenly so the tree stays shallow
+    let rest = packets - 1;
+    let mut op = [0, 1, 2, 3, 5, 6, 7][rng.below(7)];
+    let count = match op {
+        5..=7 if rest >= 2 => 2,
+        5..=7 => {
+            op = rng.below(4) as u64;
+            1
+        }
+        _ => rng.range(1, rest.min(8)),
+    };
+    let mut sizes = vec![rest / count; count];
+    for s in sizes.iter_mut().take(rest % count) {
+        *s += 1;
+    }
+    rng.shuffle(&mut sizes);
+    let children: Vec<_> = sizes.into_iter().map(|n| packet(rng, n)).collect();
+
+    let mut values = children.iter().map(|(_, v)| *v);
+    let value = match op {
+        0 => values.try_fold(0u64, u64::checked_add),
+        1 => values.try_fold(1u64, u64::checked_mul),
+        2 => values.min(),
+        3 => values.max(),
+        _ => {
+            let (a, b) = (children[0].1, children[1].1);
+            Some(u64::from(match op {
+                5 => a > b,
+                6 => a < b,
+                _ => a == b,
+            }))
+        }
+    };
+    let value = match value.filter(|&v| v <= MAX_PACKET_VALUE) {
+        Some(value) => value,
+        None => {
+            op = 2;
+            children.iter().map(|(_, v)| *v).min().unwrap()
+        }
+    };
+
+    push_bits(&mut bits, op, 3);
+    let len: usize = children.iter().map(|(b, _)| b.len()).sum();
+    if len < 1 << 15 && rng.below(2) == 0 {
+        push_bits(&mut bits, 0, 1);
+        push_bits(&mut bits, len as u64, 15);
+    } else {
+        push_bits(&mut bits, 1, 1);
+        push_bits(&mut bits, count as u64, 11);
+    }
+    for (child, _) in children {
+        bits.extend(child);
+    }
+    (bits, value)
+}
+
+/// Day 16: a BITS transmission of a single expression made of `packets` packets
+pub fn transmission(rng: &mut Rng, packets: usize) -> String {
+    let (mut bits, _) = packet(rng, packets);
+    while bits.len() % 8 != 0 {
+        bits.push(false);
+    }
+
+    let mut out = String::with_capacity(bits.len() / 4 + 1);
+    for nibble in bits.chunks(4) {
+        let n = nibble.iter().fold(0, |acc, &b| acc << 1 | u32::from(b));
+        out.push(char::from_digit(n, 16).unwrap().to_ascii_uppercase());
+    }
+    out.push('\n');
+    out
+}
+
+fn snailfish_number(rng: &mut Rng, depth: usize, out: &mut String) {
+    if depth > 0 && (depth == 4 || rng.below(3) == 0) {
+        out.push(char::from(b'0' + rng.below(10) as u8));
+    } else {
+        out.push('[');
+        snailfish_number(rng, depth + 1, out);
+        out.push(',');
+        snailfish_number(rng, depth + 1, out);
+        out.push(']');
+    }
+}
+
+/// Day 18: `lines` reduced snailfish numbers
+pub fn snailfish(rng: &mut Rng, lines: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..lines {
+        snailfish_number(rng, 0, &mut out);
+        out.push('\n');
+    }
+    out
+}
+
+/// A random input generator for one day
+#[derive(Clone, Copy)]
+pub struct Generator {
+    pub year: u64,
+    pub day: u64,
+    /// What the size controls
+    pub size: &'static str,
+    /// The size of a real input
+    pub default_size: usize,
+    /// The smallest size that makes a valid input
+    pub min_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    /// The size to generate an input of: `size` if it's big enough, or the default
+    pub fn size(&self, size: Option<usize>) -> Result<usize> {
+        match size {
+            Some(size) if size < self.min_size => bail!(
+                "the size for day {} must be at least {} ({})",
+                day_label(self.year, self.day),
+                self.min_size,
+                self.size
+            ),
+            Some(size) => Ok(size),
+            None => Ok(self.default_size),
+        }
+    }
+}
+
+/// Every day with a generator, in order
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        year: 2021,
+        day: 1,
+        size: "sonar depths",
+        default_size: 2000,
+        min_size: 1,
+        generate: depths,
+    },
+    Generator {
+        year: 2021,
+        day: 2,
+        size: "commands",
+        default_size: 1000,
+        min_size: 1,
+        generate: commands,
+    },
+    Generator {
+        year: 2021,
+        day: 3,
+        size: "diagnostic report lines",
+        default_size: 1000,
+        min_size: 1,
+        generate: diagnostics,
+    },
+    Generator {
+        year: 2021,
+        day: 4,
+        size: "bingo cards, drawn from 100 numbers",
+        default_size: 100,
+        min_size: 1,
+        generate: |rng, size| bingo(rng, 100, size),
+    },
+    Generator {
+        year: 2021,
+        day: 5,
+        size: "vent lines on a 1000 by 1000 grid",
+        default_size: 500,
+        min_size: 1,
+        generate: |rng, size| vents(rng, size, 1000),
+    },
+    Generator {
+        year: 2021,
+        day: 6,
+        size: "lanternfish",
+        default_size: 300,
+        min_size: 1,
+        generate: lanternfish,
+    },
+    Generator {
+        year: 2021,
+        day: 7,
+        size: "crabs",
+        default_size: 1000,
+        min_size: 1,
+        generate: crabs,
+    },
+    Generator {
+        year: 2021,
+        day: 8,
+        size: "notes of scrambled displays",
+        default_size: 200,
+        min_size: 1,
+        generate: displays,
+    },
+    Generator {
+        year: 2021,
+        day: 9,
+        size: "width and height of the height map",
+        default_size: 100,
+        min_size: 1,
+        generate: |rng, size| height_map(rng, size, size),
+    },
+    Generator {
+        year: 2021,
+        day: 10,
+        size: "lines of chunks",
+        default_size: 100,
+        min_size: 1,
+        generate: chunks,
+    },
+    Generator {
+        year: 2021,
+        day: 11,
+        size: "width and height of the octopus grid",
+        default_size: 10,
+        min_size: 1,
+        generate: octopuses,
+    },
+    Generator {
+        year: 2021,
+        day: 12,
+        size: "small caves, with a quarter as many big caves and twice as many tunnels",
+        default_size: 10,
+        min_size: 1,
+        generate: |rng, size| caves(rng, size, size / 4 + 1, 2 * size + 2),
+    },
+    Generator {
+        year: 2021,
+        day: 13,
+        size: "dots on the paper",
+        default_size: 800,
+        min_size: 1,
+        generate: transparent_paper,
+    },
+    Generator {
+        year: 2021,
+        day: 14,
+        size: "elements in the polymer template, with 10 kinds of element",
+        default_size: 20,
+        min_size: 1,
+        generate: |rng, size| polymer(rng, size, 10),
+    },
+    Generator {
+        year: 2021,
+        day: 15,
+        size: "width and height of the risk map",
+        default_size: 100,
+        min_size: 1,
+        generate: |rng, size| digits(rng, size, size, 1, 9),
+    },
+    Generator {
+        year: 2021,
+        day: 16,
+        size: "packets in the transmission",
+        default_size: 250,
+        min_size: 1,
+        generate: transmission,
+    },
+    Generator {
+        year: 2021,
+        day: 17,
+        size: "greatest distance from the launcher to the target area",
+        default_size: 100,
+        min_size: 2,
+        generate: target_area,
+    },
+    Generator {
+        year: 2021,
+        day: 18,
+        size: "snailfish numbers",
+        default_size: 100,
+        min_size: 1,
+        generate: snailfish,
+    },
+    Generator {
+        year: 2021,
+        day: 21,
+        size: "nothing (there are only two starting positions)",
+        default_size: 0,
+        min_size: 0,
+        generate: |rng, _| dirac_dice(rng),
+    },
+];
+
+/// Look up the generator for a day
+pub fn find_generator(year: u64, day: u64) -> Result<&'static Generator> {
+    GENERATORS
+        .iter()
+        .find(|g| g.year == year && g.day == day)
+        .ok_or_else(|| {
+            let days: Vec<_> = GENERATORS
+                .iter()
+                .map(|g| day_label(g.year, g.day))
+                .collect();
+            anyhow!(
+                "no generator for day {} (there are generators for days {})",
+                day_label(year, day),
+                days.join(", ")
+            )
+        })
+}
+
+/// Entry point for `aoc generate <day> [--seed N] [--size N]`
+///
+/// The input is written to stdout.
+pub fn generate_main(args: Vec<String>) -> Result<()> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| anyhow!("missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--seed" => seed = value()?.parse()?,
+            "--size" => size = Some(value()?.parse()?),
+            label if day.is_none() => day = Some(parse_day_label(label)?),
+            _ => bail!("unexpected argument: {}", arg),
+        }
+    }
+    let (year, day) =
+        day.ok_or_else(|| anyhow!("usage: aoc generate <day> [--seed N] [--size N]"))?;
+
+    let generator = find_generator(year, day)?;
+    let size = generator.size(size)?;
+    print!("{}", (generator.generate)(&mut Rng::new(seed), size));
+    Ok(())
+}
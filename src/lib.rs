@@ -1,81 +1,773 @@
 use std::env;
 use std::fmt::Display;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
 
 pub use anyhow::Result;
 
-pub fn aoc_main<InFilter, InType, PartOne, O1, PartTwo, O2>(
-    day: u64,
-    mut in_filter: InFilter,
-    mut part_one: PartOne,
-    mut part_two: PartTwo,
-) -> Result<()>
+use anyhow::{anyhow, bail, Context};
+
+use crate::alloc_stats::AllocStats;
+use crate::cli::{Options, Parts};
+use crate::error::Error;
+use crate::output::Format;
+use crate::stream::LineParser;
+
+pub mod all;
+pub mod alloc_stats;
+pub mod bench;
+pub mod cli;
+pub mod compression;
+pub mod crosscheck;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day2;
+pub mod day21;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod generate;
+pub mod normalize;
+pub mod output;
+pub mod parse_error;
+pub mod report;
+pub mod scaffold;
+pub mod stream;
+#[cfg(feature = "fetch")]
+pub mod submit;
+pub mod verify;
+pub mod watch;
+
+/// The year a day belongs to when none is given, as in `aoc run 5`
+pub const DEFAULT_YEAR: u64 = 2021;
+
+/// A solution to a single day's puzzle
+pub trait Solution {
+    /// The year of the puzzle, used with [`DAY`](Self::DAY) to locate the default input file
+    const YEAR: u64;
+
+    /// The day of the puzzle, used to locate the default input file
+    const DAY: u64;
+
+    /// Constants from the puzzle text that can be overridden with `--param name=value`
+    const PARAMS: &'static [Param] = &[];
+
+    /// The parsed puzzle input, shared by both parts
+    ///
+    /// Inputs and answers have to be `Send` so that a part can be solved on its own thread and
+    /// abandoned if it runs out of time; see [`solve_within`].
+    type Input: Clone + Send + 'static;
+    type Output1: Display + Send + 'static;
+    type Output2: Display + Send + 'static;
+
+    /// How to parse the input a line at a time, for days that can; see [`stream`]
+    const LINES: Option<LineParser<Self::Input>> = None;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: Self::Input, params: &Params) -> Result<Self::Output1>;
+    fn part_two(input: Self::Input, params: &Params) -> Result<Self::Output2>;
+
+    /// A picture to show under part two's answer in text output, like day 13's folded paper
+    ///
+    /// Answers themselves stay on one line, so they can be recorded, compared and submitted.
+    fn picture(_answer: &Self::Output2) -> Option<String> {
+        None
+    }
+}
+
+/// A named puzzle constant, like the number of steps to simulate
+#[derive(Clone, Copy, Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub help: &'static str,
+}
+
+/// The value of every parameter a day declares, with any overrides applied
+#[derive(Clone, Debug, Default)]
+pub struct Params(Vec<(&'static str, String)>);
+
+impl Params {
+    /// Start from the declared defaults and apply `overrides`, given as name and value pairs
+    pub fn new(declared: &'static [Param], overrides: &[(String, String)]) -> Result<Self> {
+        let mut values: Vec<_> = declared
+            .iter()
+            .map(|p| (p.name, p.default.to_owned()))
+            .collect();
+
+        for (name, value) in overrides {
+            match values.iter_mut().find(|(n, _)| n == name) {
+                Some((_, v)) => *v = value.clone(),
+                None if declared.is_empty() => {
+                    bail!("unknown parameter {} (this day has none)", name)
+                }
+                None => {
+                    let names: Vec<_> = declared.iter().map(|p| p.name).collect();
+                    bail!(
+                        "unknown parameter {} (expected one of {})",
+                        name,
+                        names.join(", ")
+                    )
+                }
+            }
+        }
+        Ok(Params(values))
+    }
+
+    /// Parse the value of a declared parameter
+    pub fn get<T>(&self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        let (_, value) = self
+            .0
+            .iter()
+            .find(|(n, _)| *n == name)
+            .ok_or_else(|| anyhow!("undeclared parameter {}", name))?;
+        value
+            .parse()
+            .with_context(|| format!("invalid value for parameter {}: {}", name, value))
+    }
+}
+
+/// Entry point for a single day's binary
+///
+/// Errors are printed and turned into an exit code; see [`error`] for what each code means.
+pub fn aoc_main<S: Solution>() -> ExitCode {
+    error::exit_code(run::<S>(env::args().skip(1).collect()))
+}
+
+/// Run a solution using the given command line arguments (excluding the program name)
+///
+/// See [`cli`] for the accepted options.
+pub fn run<S: Solution>(args: Vec<String>) -> Result<()> {
+    let opts = Options::parse(args)?;
+    let params = Params::new(S::PARAMS, &opts.params)?;
+    let sources = opts.sources(S::YEAR, S::DAY)?;
+
+    if opts.watch {
+        if sources.iter().any(|s| s.as_deref() == Some("-")) {
+            bail!("can't watch stdin for changes");
+        }
+        let paths = sources
+            .iter()
+            .map(|s| match s {
+                Some(path) => Ok(PathBuf::from(path)),
+                None => input::default_file(S::YEAR, S::DAY),
+            })
+            .collect::<Result<_>>()?;
+
+        let mut watcher = watch::Watcher::new(paths);
+        watcher.run(|| {
+            if let Err(e) = run_sources::<S>(&opts, &params, &sources) {
+                if opts.format == Format::Text {
+                    println!("Error: {:#}", e);
+                }
+            }
+        });
+    }
+
+    run_sources::<S>(&opts, &params, &sources)
+}
+
+fn run_sources<S: Solution>(
+    opts: &Options,
+    params: &Params,
+    sources: &[Option<String>],
+) -> Result<()> {
+    let multiple = sources.len() > 1;
+
+    let mut failures = 0;
+    let mut exit_code = None;
+    for source in sources {
+        let name = source
+            .clone()
+            .unwrap_or_else(|| input::default_path(S::YEAR, S::DAY));
+        if multiple && opts.format == Format::Text {
+            println!("== {} ==", name);
+        }
+
+        let result = if opts.stream {
+            stream::solve_lines::<S>(source.as_deref(), opts.parts, params, opts.timeout)
+        } else {
+            input::read_input(source.as_deref(), S::YEAR, S::DAY, opts.trailing_newline)
+                .and_then(|input| solve_within::<S>(&input, opts.parts, params, opts.timeout))
+        };
+        // A part that didn't finish is printed along with the report, so part one's answer isn't
+        // lost when part two fails
+        let failure = match result {
+            Ok(report) => {
+                output::print_report(opts.format, S::YEAR, S::DAY, &name, &report);
+                report.failure()
+            }
+            Err(e) => {
+                output::print_error(opts.format, S::YEAR, S::DAY, &name, &e);
+                Some(e)
+            }
+        };
+        if let Some(e) = failure {
+            failures += 1;
+            if !multiple {
+                return Err(e);
+            }
+            if opts.format == Format::Text {
+                println!("Error: {:#}", e);
+            }
+            exit_code.get_or_insert(error::exit_code_of(&e));
+        }
+    }
+
+    match exit_code {
+        Some(exit_code) => Err(Error::InputsFailed {
+            failed: failures,
+            exit_code,
+        }
+        .into()),
+        None => Ok(()),
+    }
+}
+
+/// The answer to one part of a puzzle and how long it took to compute
+#[derive(Clone, Debug)]
+pub struct PartReport {
+    pub answer: String,
+    pub time: Duration,
+    /// Heap usage, if built with the `alloc-stats` feature
+    pub allocs: Option<AllocStats>,
+    /// See [`Solution::picture`]
+    pub picture: Option<String>,
+}
+
+/// How one part of a puzzle turned out
+#[derive(Clone, Debug)]
+pub enum PartResult {
+    Answer(PartReport),
+    /// The part was abandoned after running for longer than this budget
+    Timeout(Duration),
+    /// The part failed, with the error rendered as `{:#}`
+    Error(String),
+}
+
+impl PartResult {
+    /// The answer, if the part finished
+    pub fn answer(&self) -> Option<&PartReport> {
+        match self {
+            PartResult::Answer(p) => Some(p),
+            PartResult::Timeout(_) | PartResult::Error(_) => None,
+        }
+    }
+
+    /// Why `part` didn't finish, tagged with the same [`Error`] it would have failed the run with
+    pub fn error(&self, part: u8) -> Option<Error> {
+        match self {
+            PartResult::Answer(_) => None,
+            PartResult::Timeout(budget) => Some(Error::Timeout {
+                part,
+                budget: *budget,
+            }),
+            PartResult::Error(message) => Some(Error::Solve {
+                part,
+                source: anyhow!("{}", message),
+            }),
+        }
+    }
+}
+
+/// Answers and wall-clock timings from a single run of a solution
+///
+/// Parts that weren't asked for are `None`.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub parse_time: Duration,
+    pub parse_allocs: Option<AllocStats>,
+    pub part_one: Option<PartResult>,
+    pub part_two: Option<PartResult>,
+}
+
+impl Report {
+    /// The parts that were asked for, numbered 1 and 2
+    pub fn parts(&self) -> impl Iterator<Item = (u8, &PartResult)> {
+        [(1, &self.part_one), (2, &self.part_two)]
+            .into_iter()
+            .filter_map(|(part, result)| result.as_ref().map(|r| (part, r)))
+    }
+
+    /// The answer to `part`, if it was asked for and finished
+    pub fn answer(&self, part: u8) -> Option<&PartReport> {
+        self.parts()
+            .find(|&(p, _)| p == part)
+            .and_then(|(_, result)| result.answer())
+    }
+
+    /// The first part that didn't finish, as an error
+    pub fn failure(&self) -> Option<anyhow::Error> {
+        self.parts()
+            .find_map(|(part, result)| result.error(part))
+            .map(Into::into)
+    }
+
+    /// Timings of every phase that finished, labelled `parse`, `part1` and `part2`
+    pub fn timings(&self) -> Vec<(&'static str, Duration)> {
+        let mut timings = vec![("parse", self.parse_time)];
+        for (part, label) in [(1, "part1"), (2, "part2")] {
+            if let Some(p) = self.answer(part) {
+                timings.push((label, p.time));
+            }
+        }
+        timings
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> Result<T>) -> Result<(T, Duration, Option<AllocStats>)> {
+    let start = Instant::now();
+    let (out, allocs) = alloc_stats::measure(f);
+    let time = start.elapsed();
+    Ok((out?, time, allocs))
+}
+
+/// Parse the input and solve the requested parts, timing each phase separately
+pub fn solve<S: Solution>(input: &str, parts: Parts) -> Result<Report> {
+    solve_with::<S>(input, parts, &Params::new(S::PARAMS, &[])?)
+}
+
+/// Like [`solve`], with the given parameters instead of the defaults
+///
+/// A part that fails fails the whole run, with the first failure's error.
+pub fn solve_with<S: Solution>(input: &str, parts: Parts, params: &Params) -> Result<Report> {
+    let report = solve_within::<S>(input, parts, params, None)?;
+    match report.failure() {
+        Some(e) => Err(e),
+        None => Ok(report),
+    }
+}
+
+/// Solve one part, giving up if it takes longer than `budget`
+///
+/// With a budget the part runs on a thread of its own. Threads can't be killed, so one that runs
+/// out of time is left running in the background and its answer thrown away if it ever arrives.
+/// The answer is formatted and drawn by `picture` afterwards, so that doesn't count towards the
+/// part's time.
+fn timed_part<T, F>(
+    part: u8,
+    budget: Option<Duration>,
+    picture: fn(&T) -> Option<String>,
+    f: F,
+) -> PartResult
 where
-    InType: Clone,
-    InFilter: FnMut(&str) -> Result<InType>,
-    PartOne: FnMut(InType) -> Result<O1>,
-    PartTwo: FnMut(InType) -> Result<O2>,
-    O1: Display,
-    O2: Display,
+    T: Display + Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
 {
-    let input = input::puzzle_input(env::args(), day).unwrap();
-    let input = in_filter(&input)?;
-    println!("Part 1 Count: {}", part_one(input.clone())?);
-    println!("Part 2 Count: {}", part_two(input)?);
-    Ok(())
+    let finished = |result: Result<(T, Duration, Option<AllocStats>)>| match result {
+        Ok((answer, time, allocs)) => PartResult::Answer(PartReport {
+            answer: answer.to_string(),
+            time,
+            allocs,
+            picture: picture(&answer),
+        }),
+        Err(e) => PartResult::Error(format!("{:#}", e)),
+    };
+
+    let Some(budget) = budget else {
+        return finished(timed(f));
+    };
+
+    let (tx, rx) = mpsc::channel();
+    // Give the part as much stack as it would have had on the main thread
+    let handle = thread::Builder::new()
+        .name(format!("part {}", part))
+        .stack_size(8 << 20)
+        .spawn(move || {
+            let _ = tx.send(timed(f));
+        });
+    let handle = match handle {
+        Ok(handle) => handle,
+        Err(e) => return PartResult::Error(format!("failed to start a thread: {}", e)),
+    };
+
+    match rx.recv_timeout(budget) {
+        Ok(result) => finished(result),
+        Err(RecvTimeoutError::Timeout) => PartResult::Timeout(budget),
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(panic) => std::panic::resume_unwind(panic),
+            Ok(()) => unreachable!("the part finished without sending its answer"),
+        },
+    }
+}
+
+/// Like [`solve_with`], abandoning any part that runs for longer than `budget`
+///
+/// Only parsing the input can fail the run. A part that fails or runs out of time is recorded in
+/// the report, after any answer to the other part, and [`Report::failure`] turns it back into an
+/// [`Error::Solve`] or [`Error::Timeout`].
+pub fn solve_within<S: Solution>(
+    input: &str,
+    parts: Parts,
+    params: &Params,
+    budget: Option<Duration>,
+) -> Result<Report> {
+    solve_parsed::<S>(|| S::parse(input), parts, params, budget)
+}
+
+/// Like [`solve_within`], with the input parsed by `parse`
+///
+/// Errors from `parse` that are already tagged with an [`Error`], like failing to read the
+/// input, keep their tag. Anything else is a parse error.
+pub(crate) fn solve_parsed<S: Solution>(
+    parse: impl FnOnce() -> Result<S::Input>,
+    parts: Parts,
+    params: &Params,
+    budget: Option<Duration>,
+) -> Result<Report> {
+    let (input, parse_time, parse_allocs) = timed(parse).map_err(|e| {
+        if e.chain().any(|cause| cause.is::<Error>()) {
+            e
+        } else {
+            Error::Parse(e).into()
+        }
+    })?;
+
+    let part_one = parts.one().then(|| {
+        let part_input = input.clone();
+        let part_params = params.clone();
+        timed_part(
+            1,
+            budget,
+            |_| None,
+            move || S::part_one(part_input, &part_params),
+        )
+    });
+
+    let part_two = parts.two().then(|| {
+        let part_params = params.clone();
+        timed_part(2, budget, S::picture, move || {
+            S::part_two(input, &part_params)
+        })
+    });
+
+    Ok(Report {
+        parse_time,
+        parse_allocs,
+        part_one,
+        part_two,
+    })
+}
+
+/// A registered day, with its solution erased to a plain function pointer
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u64,
+    pub day: u64,
+    pub params: &'static [Param],
+    pub run: fn(Vec<String>) -> Result<()>,
+    pub solve: fn(&str, Parts) -> Result<Report>,
+    /// Like `solve`, recording parts that fail or run out of time in the report (see
+    /// [`solve_within`])
+    pub solve_within: fn(&str, Parts, Option<Duration>) -> Result<Report>,
+}
+
+/// [`solve_within`] with the default parameters, for the registry
+fn solve_within_defaults<S: Solution>(
+    input: &str,
+    parts: Parts,
+    budget: Option<Duration>,
+) -> Result<Report> {
+    solve_within::<S>(input, parts, &Params::new(S::PARAMS, &[])?, budget)
+}
+
+macro_rules! registry {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        /// Every solved day, in order
+        pub static DAYS: &[Day] = &[
+            $(Day {
+                year: <$module::$solution as Solution>::YEAR,
+                day: <$module::$solution as Solution>::DAY,
+                params: <$module::$solution as Solution>::PARAMS,
+                run: run::<$module::$solution>,
+                solve: solve::<$module::$solution>,
+                solve_within: solve_within_defaults::<$module::$solution>,
+            }),*
+        ];
+    };
+}
+
+registry![
+    day1::Day1,
+    day2::Day2,
+    day3::Day3,
+    day4::Day4,
+    day5::Day5,
+    day6::Day6,
+    day7::Day7,
+    day8::Day8,
+    day9::Day9,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    day13::Day13,
+    day14::Day14,
+    day15::Day15,
+    day16::Day16,
+    day17::Day17,
+    day18::Day18,
+    day21::Day21,
+];
+
+impl Day {
+    /// How the day is written on the command line and in data files: `N` for a day of
+    /// [`DEFAULT_YEAR`], or `YEAR/N`
+    pub fn label(&self) -> String {
+        day_label(self.year, self.day)
+    }
+}
+
+/// Format a year and day the way [`parse_day_label`] reads them
+pub fn day_label(year: u64, day: u64) -> String {
+    if year == DEFAULT_YEAR {
+        day.to_string()
+    } else {
+        format!("{}/{}", year, day)
+    }
+}
+
+/// Parse a day given as `N` (a day of [`DEFAULT_YEAR`]) or `YEAR/N` into a year and day
+pub fn parse_day_label(label: &str) -> Result<(u64, u64)> {
+    let parse = |n: &str| n.parse().with_context(|| format!("invalid day: {}", label));
+    match label.split_once('/') {
+        Some((year, day)) => Ok((parse(year)?, parse(day)?)),
+        None => Ok((DEFAULT_YEAR, parse(label)?)),
+    }
+}
+
+/// Look up a day in the registry
+pub fn find_day(year: u64, day: u64) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|d| d.year == year && d.day == day)
+        .ok_or_else(|| anyhow!("no solution for day {} of {}", day, year))
+}
+
+/// Look up a day given as `N` or `YEAR/N` on the command line
+pub fn parse_day(label: &str) -> Result<&'static Day> {
+    let (year, day) = parse_day_label(label)?;
+    find_day(year, day)
+}
+
+/// Entry point for the `aoc` dispatcher binary
+pub fn dispatch_main() -> ExitCode {
+    error::exit_code(dispatch(env::args().skip(1)))
 }
 
-mod input {
-    use super::Result;
-    use anyhow::{anyhow, ensure};
+fn dispatch(mut args: impl Iterator<Item = String>) -> Result<()> {
+    match args.next().as_deref() {
+        Some("run") => {
+            let day = args
+                .next()
+                .ok_or_else(|| anyhow!("usage: aoc run <day> [input]"))?;
+            let day = parse_day(&day)?;
+            (day.run)(args.collect())
+        }
+        Some("all") => all::all_main(args.collect()),
+        Some("report") => report::report_main(args.collect()),
+        Some("bench") => bench::bench_main(args.collect()),
+        Some("verify") => verify::verify_main(args.collect()),
+        #[cfg(feature = "fetch")]
+        Some("submit") => submit::submit_main(args.collect()),
+        Some("new") => scaffold::new_main(args.collect()),
+        Some("generate") => generate::generate_main(args.collect()),
+        Some("crosscheck") => crosscheck::crosscheck_main(args.collect()),
+        Some("list") => {
+            for day in DAYS {
+                print!("{}", day.label());
+                for p in day.params {
+                    print!(" {}={}", p.name, p.default);
+                }
+                println!();
+            }
+            Ok(())
+        }
+        Some(cmd) => bail!("unknown command: {}", cmd),
+        None => bail!(
+            "usage: aoc <run|all|report|bench|verify|submit|new|generate|crosscheck|list> ..."
+        ),
+    }
+}
+
+pub(crate) mod input {
+    use super::{Error, Result};
+    use crate::compression::{self, decompress};
+    use crate::normalize::{normalize, TrailingNewline};
+    use anyhow::anyhow;
     use std::env;
     use std::fs::{self, File};
     use std::io;
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
+
+    /// Read all bytes from a reader into a new String, decompressing them if they're compressed
+    fn read_to_string<R: io::Read>(reader: R) -> io::Result<String> {
+        io::read_to_string(decompress(reader)?)
+    }
+
+    /// Find `subpath` under `dir` or its nearest ancestor, or failing that a compressed copy of it
+    ///
+    /// Returns the path that was found along with the open file.
+    fn find_input_file<P: AsRef<Path>>(dir: P, subpath: &str) -> Option<(PathBuf, File)> {
+        let candidates = [""].iter().chain(compression::EXTENSIONS);
+        candidates
+            .map(|ext| format!("{}{}", subpath, ext))
+            .find_map(|subpath| {
+                dir.as_ref().ancestors().find_map(|dir| {
+                    let path = dir.join(&subpath);
+                    File::open(&path).ok().map(|file| (path, file))
+                })
+            })
+    }
 
-    fn file_arg(mut args: env::Args) -> Result<Option<String>> {
-        ensure!(args.len() <= 2, "too many arguments");
+    /// The default input file for a day, if there is one
+    fn find_default_input(year: u64, day: u64) -> Result<Option<(PathBuf, File)>> {
+        let path = default_path(year, day);
+        // A compressed input in the single-year layout is still found when there's no plain file
+        // in either layout
+        let legacy = format!("input/day{}.txt", day);
+        let cwd = env::current_dir()?;
+        Ok(find_input_file(&cwd, &path).or_else(|| find_input_file(&cwd, &legacy)))
+    }
 
-        Ok(args.nth(1))
+    /// Find the nearest ancestor of the current directory containing `subpath`
+    pub fn find_root<Q: AsRef<Path>>(subpath: Q) -> Result<Option<PathBuf>> {
+        let cwd = env::current_dir()?;
+        Ok(cwd
+            .ancestors()
+            .find(|dir| dir.join(subpath.as_ref()).exists())
+            .map(Path::to_path_buf))
     }
 
-    /// Read all bytes from a reader into a new String
+    /// `DIR/YEAR/dayN.EXT`, unless only the single-year `DIR/dayN.EXT` exists
     ///
-    /// Replace with std::io::read_to_string() if that is ever stabilized
-    fn read_to_string<R: io::Read>(reader: &mut R) -> io::Result<String> {
-        let mut buf = String::new();
-        reader.read_to_string(&mut buf)?;
-        Ok(buf)
-    }
-
-    fn find_input_file<P: AsRef<Path>, Q: AsRef<Path>>(dir: P, subpath: Q) -> Result<File> {
-        fn inner(dir: &Path, subpath: &Path) -> Result<File> {
-            for dir in dir.ancestors() {
-                let path = dir.join(subpath);
-                if let Ok(f) = File::open(path) {
-                    return Ok(f);
-                }
-            }
-            Err(anyhow!("no input file found"))
+    /// Paths are relative to the nearest ancestor of the current directory containing them.
+    pub(crate) fn year_path(dir: &str, year: u64, day: u64, ext: &str) -> String {
+        let path = format!("{}/{}/day{}{}", dir, year, day, ext);
+        let legacy = format!("{}/day{}{}", dir, day, ext);
+        match (find_root(&path), find_root(&legacy)) {
+            (Ok(None), Ok(Some(_))) => legacy,
+            _ => path,
         }
-        inner(dir.as_ref(), subpath.as_ref())
     }
 
-    pub fn puzzle_input(args: env::Args, day: u64) -> Result<String> {
-        let input = match file_arg(args)? {
-            Some(filename) => {
-                if filename == "-" {
-                    let mut stdin = io::stdin();
-                    read_to_string(&mut stdin)?
-                } else {
-                    fs::read_to_string(filename)?
-                }
-            }
-            None => {
-                let mut file =
-                    find_input_file(env::current_dir()?, format!("input/day{}.txt", day))?;
-                read_to_string(&mut file)?
+    /// The input file for a day: `input/YEAR/dayN.txt`, or `input/dayN.txt` if only that exists
+    pub fn default_path(year: u64, day: u64) -> String {
+        year_path("input", year, day, ".txt")
+    }
+
+    /// Where the default input file for a day is, or would be if it existed
+    ///
+    /// An existing file is the one [`read_input`] reads, which may be compressed.
+    pub fn default_file(year: u64, day: u64) -> Result<PathBuf> {
+        if let Some((found, _)) = find_default_input(year, day)? {
+            return Ok(found);
+        }
+        let path = default_path(year, day);
+        Ok(match find_root(&path)? {
+            Some(root) => root.join(path),
+            None => env::current_dir()?.join(path),
+        })
+    }
+
+    /// Every `examples/YEAR/dayN/*.txt` (or `examples/dayN/*.txt`) file, sorted by name
+    pub fn example_files(year: u64, day: u64) -> Result<Vec<PathBuf>> {
+        let subpath = year_path("examples", year, day, "");
+        let dir = match find_root(&subpath)? {
+            Some(root) => root.join(subpath),
+            None => return Ok(Vec::new()),
+        };
+
+        let mut files = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                files.push(path);
             }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// Read the puzzle input from `filename` (`-` for stdin), or the default input file for a day
+    ///
+    /// The input is normalised as described in [`crate::normalize`].
+    pub fn puzzle_input(filename: Option<&str>, year: u64, day: u64) -> Result<String> {
+        read_input(filename, year, day, TrailingNewline::default())
+    }
+
+    /// Like [`puzzle_input`], with a choice of what to do with trailing newlines
+    pub fn read_input(
+        filename: Option<&str>,
+        year: u64,
+        day: u64,
+        trailing: TrailingNewline,
+    ) -> Result<String> {
+        let input = match filename {
+            Some("-") => read_to_string(io::stdin()).map_err(|e| Error::read("stdin", e))?,
+            Some(filename) => File::open(filename)
+                .and_then(read_to_string)
+                .map_err(|e| Error::read(filename, e))?,
+            None => match find_default_input(year, day)? {
+                Some((found, file)) => read_to_string(file)
+                    .map_err(|e| Error::read(&found.display().to_string(), e))?,
+                None => fetch_input(year, day).map_err(|e| Error::InputNotFound {
+                    path: default_path(year, day),
+                    reason: Some(format!("{:#}", e)),
+                })?,
+            },
         };
-        Ok(input)
+        Ok(normalize(&input, trailing))
+    }
+
+    /// Where a downloaded input for a day should be cached
+    #[cfg(feature = "fetch")]
+    pub fn cache_path(year: u64, day: u64) -> Result<PathBuf> {
+        let root = match find_root("input")? {
+            Some(root) => root,
+            None => env::current_dir()?,
+        };
+        Ok(root.join(default_path(year, day)))
+    }
+
+    #[cfg(feature = "fetch")]
+    fn fetch_input(year: u64, day: u64) -> Result<String> {
+        use crate::fetch::Client;
+        use anyhow::Context;
+
+        match Client::from_env()? {
+            Some(client) => client
+                .cached_input(year, day, cache_path(year, day)?)
+                .context("failed to download input"),
+            None => Err(anyhow!("set AOC_SESSION to download it")),
+        }
+    }
+
+    #[cfg(not(feature = "fetch"))]
+    fn fetch_input(_year: u64, _day: u64) -> Result<String> {
+        Err(anyhow!(
+            "built without the fetch feature, so it can't be downloaded"
+        ))
     }
 }
@@ -0,0 +1,57 @@
+//! Turning puzzle input into canonical text before any day sees it
+//!
+//! Inputs saved on Windows or copied out of a browser can have a byte order mark, CRLF line
+//! endings or extra blank lines at the end. Every input is normalised so that it has none of
+//! those, and (by default) ends with exactly one newline.
+
+use std::str::FromStr;
+
+use anyhow::bail;
+
+/// What to do with whitespace after the last line of input
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TrailingNewline {
+    /// End with exactly one newline, dropping any trailing blank lines
+    #[default]
+    One,
+    /// Drop the final newline as well as any trailing blank lines
+    None,
+    /// Leave the end of the input as it is
+    Keep,
+}
+
+impl FromStr for TrailingNewline {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "one" => Ok(TrailingNewline::One),
+            "none" => Ok(TrailingNewline::None),
+            "keep" => Ok(TrailingNewline::Keep),
+            _ => bail!(
+                "invalid trailing newline mode: {} (expected one, none or keep)",
+                s
+            ),
+        }
+    }
+}
+
+/// Strip a byte order mark, convert CRLF to LF and tidy up the end of the input
+pub fn normalize(input: &str, trailing: TrailingNewline) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut out = input.replace("\r\n", "\n");
+
+    if trailing != TrailingNewline::Keep {
+        // Keep any trailing spaces on the last line with content, but nothing after it
+        let content_end = out.trim_end().len();
+        let line_end = out[content_end..]
+            .find('\n')
+            .map_or(out.len(), |i| content_end + i);
+        out.truncate(line_end);
+
+        if trailing == TrailingNewline::One && !out.is_empty() {
+            out.push('\n');
+        }
+    }
+    out
+}
@@ -0,0 +1,138 @@
+//! Printing results as human-readable text or newline-delimited JSON
+//!
+//! In JSON mode every line of output is a single object: one per part with the year, day,
+//! input, part, answer and timings in nanoseconds, or one with an `error` field if the input
+//! couldn't be solved. A part that failed or ran out of time gets an `error` in place of its
+//! answer.
+//!
+//! ```text
+//! {"year":2021,"day":1,"input":"day1.txt","part":1,"answer":"1688","parse_ns":6103,"solve_ns":229}
+//! {"year":2021,"day":1,"input":"day1.txt","part":2,"answer":"1728","parse_ns":6103,"solve_ns":512}
+//! {"year":2021,"day":5,"input":"day5.txt","error":"no input file found"}
+//! {"year":2021,"day":12,"input":"caves.txt","part":2,"error":"part 2 timed out after 1.00s"}
+//! ```
+//!
+//! With the `alloc-stats` feature, both formats also include the heap usage of each phase.
+
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use anyhow::bail;
+
+use crate::{PartResult, Report};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("unknown output format: {}", s),
+        }
+    }
+}
+
+/// Quote and escape a string for use in JSON
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Print the answers in `report`
+///
+/// Text mode leaves parts that didn't finish to the caller, like [`print_error`].
+pub fn print_report(format: Format, year: u64, day: u64, input: &str, report: &Report) {
+    match format {
+        Format::Text => {
+            println!("Parse Time: {:?}", report.parse_time);
+            if let Some(allocs) = report.parse_allocs {
+                println!("Parse Allocs: {}", allocs);
+            }
+            for (part, result) in report.parts() {
+                let Some(p) = result.answer() else { continue };
+                println!("Part {} Count: {} ({:?})", part, p.answer, p.time);
+                if let Some(picture) = &p.picture {
+                    println!("{}", picture);
+                }
+                if let Some(allocs) = p.allocs {
+                    println!("Part {} Allocs: {}", part, allocs);
+                }
+            }
+        }
+        Format::Json => {
+            for (part, result) in report.parts() {
+                let PartResult::Answer(p) = result else {
+                    let err =
+                        anyhow::Error::from(result.error(part).expect("the part didn't finish"));
+                    println!(
+                        "{{\"year\":{},\"day\":{},\"input\":{},\"part\":{},\"error\":{}}}",
+                        year,
+                        day,
+                        json_string(input),
+                        part,
+                        json_string(&format!("{:#}", err))
+                    );
+                    continue;
+                };
+                let mut allocs = String::new();
+                for (phase, stats) in [("parse", report.parse_allocs), ("solve", p.allocs)] {
+                    if let Some(s) = stats {
+                        write!(
+                            allocs,
+                            ",\"{0}_allocs\":{1},\"{0}_bytes\":{2},\"{0}_peak_bytes\":{3}",
+                            phase, s.allocations, s.bytes, s.peak_bytes
+                        )
+                        .unwrap();
+                    }
+                }
+                println!(
+                    "{{\"year\":{},\"day\":{},\"input\":{},\"part\":{},\"answer\":{},\"parse_ns\":{},\"solve_ns\":{}{}}}",
+                    year,
+                    day,
+                    json_string(input),
+                    part,
+                    json_string(&p.answer),
+                    report.parse_time.as_nanos(),
+                    p.time.as_nanos(),
+                    allocs
+                );
+            }
+        }
+    }
+}
+
+/// Print an error that stopped a day from being solved
+///
+/// Text mode leaves this to the caller, which will report the error when it's returned.
+pub fn print_error(format: Format, year: u64, day: u64, input: &str, err: &anyhow::Error) {
+    if format == Format::Json {
+        println!(
+            "{{\"year\":{},\"day\":{},\"input\":{},\"error\":{}}}",
+            year,
+            day,
+            json_string(input),
+            json_string(&format!("{:#}", err))
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Readable errors for malformed puzzle input
+//!
+//! A nom error only carries the unparsed remainder of the input. [`ParseError`] turns that back
+//! into a line and column in the original input and shows the offending line with a caret:
+//!
+//! ```text
+//! line 3, column 5: expected digit
+//!   |
+//! 3 | 0,9 => 5,9
+//!   |     ^
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use nom::{Finish, IResult};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+    pub message: String,
+}
+
+/// Byte offset of `rest` within `input`
+///
+/// `rest` is normally a subslice of `input`; anything else is treated as a suffix.
+fn offset(input: &str, rest: &[u8]) -> usize {
+    let start = input.as_ptr() as usize;
+    let pos = rest.as_ptr() as usize;
+    let mut offset = if pos >= start && pos + rest.len() <= start + input.len() {
+        pos - start
+    } else {
+        input.len().saturating_sub(rest.len())
+    };
+    // Byte parsers can stop partway through a character
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+impl ParseError {
+    /// An error at the start of `rest`, which must be a subslice of `input`
+    pub fn at<R: AsRef<[u8]>, M: Into<String>>(input: &str, rest: R, message: M) -> Self {
+        let offset = offset(input, rest.as_ref());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Convert a nom error from parsing `input`, as either text or bytes
+    pub fn from_nom<R: AsRef<[u8]>>(input: &str, err: nom::error::Error<R>) -> Self {
+        let message = format!("expected {}", err.code.description().to_lowercase());
+        ParseError::at(input, err.input, message)
+    }
+
+    /// The value of every digit in `line`, a subslice of `input`, failing at the first non-digit
+    pub fn digits(input: &str, line: &str) -> Result<Vec<u8>, Self> {
+        line.char_indices()
+            .map(|(i, c)| match c.to_digit(10) {
+                Some(d) => Ok(d as u8),
+                None => Err(ParseError::at(input, &line[i..], "expected digit")),
+            })
+            .collect()
+    }
+
+    /// Fail if anything other than whitespace is left over after parsing `input`
+    pub fn check_trailing<R: AsRef<[u8]>>(input: &str, rest: R) -> Result<(), Self> {
+        let rest = rest.as_ref();
+        match rest.iter().position(|b| !b.is_ascii_whitespace()) {
+            Some(i) => Err(ParseError::at(
+                input,
+                &rest[i..],
+                "unexpected trailing input",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Like [`check_trailing`](Self::check_trailing), for parsers built from `many0` and friends
+    ///
+    /// Those stop quietly at the first item they can't parse, so anything left over is run through
+    /// `item` to find out exactly where that item went wrong.
+    pub fn check_rest<R, O, F>(input: &str, rest: R, mut item: F) -> Result<(), Self>
+    where
+        R: AsRef<[u8]> + Clone,
+        F: FnMut(R) -> IResult<R, O>,
+    {
+        ParseError::check_trailing(input, rest.clone()).map_err(|trailing| {
+            match item(rest).finish() {
+                Err(e) => ParseError::from_nom(input, e),
+                Ok(_) => trailing,
+            }
+        })
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.source_line)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
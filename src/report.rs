@@ -0,0 +1,245 @@
+//! Status reports of every day's answers and runtimes
+//!
+//! `aoc report` solves each day against its default input and writes a Markdown table, ready to
+//! paste into the README, with an optional HTML copy. Days are solved one at a time rather than
+//! on a pool of threads, so that neither timings nor allocation counts pick up work done for
+//! other days.
+//!
+//! The status column compares the answers with the answers files `aoc verify` uses. Answers can
+//! be masked with `--mask`, which shows a short hash of each one instead, so a report can be
+//! published without giving away the answers to anyone else's puzzles.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::time::Duration;
+
+use anyhow::anyhow;
+
+use crate::all::solve_all;
+use crate::verify::{Answers, Verdict};
+use crate::{parse_day, Day, PartResult, Report, Result, DAYS};
+
+/// A rendered table of results, one row per day
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Table {
+    pub header: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+    /// Days that were solved, out of all the rows
+    pub solved: usize,
+    /// Time spent parsing and solving, over every day that was solved
+    pub total: Duration,
+}
+
+/// A short hash of an answer, the same every time it's generated
+///
+/// This is 32 bits of 64-bit FNV-1a, enough to tell whether two answers are the same. It only
+/// keeps answers from casual readers: most answers are small numbers, and hashing every candidate
+/// until one matches finds them in seconds.
+pub fn mask(answer: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in answer.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:08x}", hash >> 32)
+}
+
+fn status(verdicts: &[Verdict; 2]) -> &'static str {
+    if verdicts.iter().any(Verdict::is_fail) {
+        "wrong"
+    } else if verdicts.iter().all(|v| *v == Verdict::Pass) {
+        "verified"
+    } else {
+        "unverified"
+    }
+}
+
+/// Build the table for `days` from their results, in the same order
+pub fn table(days: &[&'static Day], results: &[Result<Report>], masked: bool) -> Result<Table> {
+    let show_allocs = results.iter().flatten().any(|r| r.parse_allocs.is_some());
+
+    let mut table = Table {
+        header: vec![
+            "Day",
+            "Status",
+            "Part 1",
+            "Part 2",
+            "Parse",
+            "Part 1 time",
+            "Part 2 time",
+        ],
+        ..Table::default()
+    };
+    if show_allocs {
+        table.header.push("Allocations (parse / 1 / 2)");
+    }
+
+    for (day, result) in days.iter().zip(results) {
+        let mut row = vec![day.label()];
+        match result {
+            Ok(report) => {
+                let answers = Answers::load(day.year, day.day)?.unwrap_or_default();
+                let parts = [
+                    (&answers.part_one, &report.part_one),
+                    (&answers.part_two, &report.part_two),
+                ];
+                let verdicts = parts.map(|(expected, actual)| {
+                    let actual = actual.as_ref().and_then(PartResult::answer);
+                    Verdict::check(expected.as_deref(), actual.map(|p| p.answer.as_str()))
+                });
+                row.push(status(&verdicts).to_owned());
+
+                for (_, part) in parts {
+                    row.push(match part {
+                        Some(PartResult::Answer(p)) if masked => mask(&p.answer),
+                        Some(PartResult::Answer(p)) => p.answer.clone(),
+                        Some(PartResult::Timeout(_)) => String::from("timed out"),
+                        Some(PartResult::Error(_)) => String::from("failed"),
+                        None => String::from("-"),
+                    });
+                }
+                row.push(format!("{:.2?}", report.parse_time));
+                for part in [1, 2] {
+                    row.push(
+                        report
+                            .answer(part)
+                            .map_or(String::from("-"), |p| format!("{:.2?}", p.time)),
+                    );
+                }
+                if show_allocs {
+                    let counts: Vec<_> = [
+                        report.parse_allocs,
+                        report.answer(1).and_then(|p| p.allocs),
+                        report.answer(2).and_then(|p| p.allocs),
+                    ]
+                    .iter()
+                    .map(|a| a.map_or(String::from("-"), |a| a.allocations.to_string()))
+                    .collect();
+                    row.push(counts.join(" / "));
+                }
+
+                if report.failure().is_none() {
+                    table.solved += 1;
+                }
+                table.total += report.timings().iter().map(|(_, t)| *t).sum::<Duration>();
+            }
+            Err(e) => {
+                row.push(String::from("error"));
+                row.push(format!("{:#}", e));
+            }
+        }
+        row.resize(table.header.len(), String::from("-"));
+        table.rows.push(row);
+    }
+    Ok(table)
+}
+
+impl Table {
+    fn summary(&self) -> String {
+        format!(
+            "{} of {} days solved in {:.2?}.",
+            self.solved,
+            self.rows.len(),
+            self.total
+        )
+    }
+
+    /// Render the table as GitHub-flavoured Markdown
+    pub fn markdown(&self) -> String {
+        let cell = |s: &str| s.replace('|', "\\|").trim_end().replace('\n', "<br>");
+
+        let mut out = String::new();
+        writeln!(out, "| {} |", self.header.join(" | ")).unwrap();
+        let rule: Vec<_> = self
+            .header
+            .iter()
+            .enumerate()
+            // Timings and counts line up on the right, everything before them on the left
+            .map(|(i, _)| if i < 4 { "---" } else { "---:" })
+            .collect();
+        writeln!(out, "| {} |", rule.join(" | ")).unwrap();
+        for row in &self.rows {
+            let cells: Vec<_> = row.iter().map(|c| cell(c)).collect();
+            writeln!(out, "| {} |", cells.join(" | ")).unwrap();
+        }
+        writeln!(out, "\n{}", self.summary()).unwrap();
+        out
+    }
+
+    /// Render the table as a standalone HTML page
+    pub fn html(&self) -> String {
+        let escape = |s: &str| {
+            s.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        };
+
+        let mut out = String::from(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Advent of Code</title>\n</head>\n<body>\n<table>\n<tr>",
+        );
+        for h in &self.header {
+            write!(out, "<th>{}</th>", escape(h)).unwrap();
+        }
+        out.push_str("</tr>\n");
+        for row in &self.rows {
+            out.push_str("<tr>");
+            for c in row {
+                if c.trim_end().contains('\n') {
+                    write!(out, "<td><pre>{}</pre></td>", escape(c.trim_end())).unwrap();
+                } else {
+                    write!(out, "<td>{}</td>", escape(c)).unwrap();
+                }
+            }
+            out.push_str("</tr>\n");
+        }
+        write!(
+            out,
+            "</table>\n<p>{}</p>\n</body>\n</html>\n",
+            escape(&self.summary())
+        )
+        .unwrap();
+        out
+    }
+}
+
+/// Entry point for `aoc report [day...] [--mask] [--output PATH] [--html PATH]`
+///
+/// The Markdown table goes to stdout unless `--output` is given. Days that fail are reported in
+/// the table rather than as an error.
+pub fn report_main(args: Vec<String>) -> Result<()> {
+    let mut days = Vec::new();
+    let mut masked = false;
+    let mut output = None;
+    let mut html = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| anyhow!("missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--mask" => masked = true,
+            "-o" | "--output" => output = Some(value()?),
+            "--html" => html = Some(value()?),
+            day => days.push(parse_day(day)?),
+        }
+    }
+    if days.is_empty() {
+        days = DAYS.iter().collect();
+    }
+
+    let results = solve_all(&days, 1, None);
+    let table = table(&days, &results, masked)?;
+
+    match output {
+        Some(path) => fs::write(path, table.markdown())?,
+        None => print!("{}", table.markdown()),
+    }
+    if let Some(path) = html {
+        fs::write(path, table.html())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,183 @@
+//! Generating the boilerplate for a new day
+//!
+//! `aoc new N` writes `src/dayN.rs` (a solution with a nom parser in `mod parse`, like day 13),
+//! the `src/bin/dayN.rs` wrapper and an `examples/dayN/` folder, and adds the day to the `mod` list
+//! and registry in `src/lib.rs`. No input file is written, so the real input can still be fetched.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, ensure};
+
+use crate::{input, Result, DEFAULT_YEAR};
+
+fn solution(day: u64) -> String {
+    format!(
+        r#"use crate::parse_error::ParseError;
+use crate::{{Params, Result, Solution}};
+use nom::Finish;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const YEAR: u64 = {year};
+    const DAY: u64 = {day};
+
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        let (rest, out) = parse::input(input)
+            .finish()
+            .map_err(|e| ParseError::from_nom(input, e))?;
+        ParseError::check_trailing(input, rest)?;
+        Ok(out)
+    }}
+
+    fn part_one(input: Self::Input, _params: &Params) -> Result<Self::Output1> {{
+        part_one(input)
+    }}
+
+    fn part_two(input: Self::Input, _params: &Params) -> Result<Self::Output2> {{
+        part_two(input)
+    }}
+}}
+
+// Placeholder answers, so the new day runs (and the tests over every day pass) before it's solved
+fn part_one(_input: Vec<u64>) -> Result<u64> {{
+    Ok(0)
+}}
+
+fn part_two(_input: Vec<u64>) -> Result<u64> {{
+    Ok(0)
+}}
+
+mod parse {{
+    use nom::{{character::complete::line_ending, multi::many0, sequence::terminated, IResult}};
+
+    pub(super) fn input(i: &str) -> IResult<&str, Vec<u64>> {{
+        many0(terminated(nom::character::complete::u64, line_ending))(i)
+    }}
+}}
+"#,
+        year = DEFAULT_YEAR,
+        day = day
+    )
+}
+
+fn binary(day: u64) -> String {
+    format!(
+        r#"use std::process::ExitCode;
+
+use aoc::{{aoc_main, day{day}::Day{day}}};
+
+fn main() -> ExitCode {{
+    aoc_main::<Day{day}>()
+}}
+"#,
+        day = day
+    )
+}
+
+/// The day of a `dayN::DayN,` registry entry
+fn registered_day(line: &str) -> Option<u64> {
+    let (module, _) = line.trim().strip_prefix("day")?.split_once("::")?;
+    module.parse().ok()
+}
+
+/// Add `pub mod dayN;` and a registry entry for `day` to the source of `lib.rs`
+pub fn register(lib: &str, day: u64) -> Result<String> {
+    let mut lines: Vec<String> = lib.lines().map(str::to_owned).collect();
+
+    // rustfmt keeps the module list sorted as strings, so day10 comes before day2
+    let module = format!("day{}", day);
+    let mods: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| {
+            l.strip_prefix("pub mod day")
+                .and_then(|rest| rest.strip_suffix(';'))
+                .map(|n| (i, format!("day{}", n)))
+        })
+        .collect();
+    ensure!(!mods.is_empty(), "no day modules found in lib.rs");
+    ensure!(
+        mods.iter().all(|(_, m)| *m != module),
+        "day {} is already registered",
+        day
+    );
+    let at = mods
+        .iter()
+        .find(|(_, m)| *m > module)
+        .map_or(mods.last().unwrap().0 + 1, |(i, _)| *i);
+    lines.insert(at, format!("pub mod {};", module));
+
+    // The registry is in day order
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("registry!["))
+        .ok_or_else(|| anyhow!("no registry found in lib.rs"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| l == "];")
+            .ok_or_else(|| anyhow!("registry in lib.rs isn't closed"))?;
+    let at = (start + 1..end)
+        .find(|&i| registered_day(&lines[i]).is_some_and(|d| d > day))
+        .unwrap_or(end);
+    lines.insert(at, format!("    day{0}::Day{0},", day));
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    Ok(out)
+}
+
+/// Create every file for a new day under `root`, returning the files that were written
+pub fn generate(root: &Path, day: u64) -> Result<Vec<PathBuf>> {
+    ensure!((1..=25).contains(&day), "there's no day {}", day);
+
+    let module = root.join(format!("src/day{}.rs", day));
+    let bin = root.join(format!("src/bin/day{}.rs", day));
+    for path in [&module, &bin] {
+        if path.exists() {
+            bail!("{} already exists", path.display());
+        }
+    }
+
+    let lib_path = root.join("src/lib.rs");
+    let lib = register(&fs::read_to_string(&lib_path)?, day)?;
+
+    let examples = root.join(format!("examples/day{}", day));
+    let example = examples.join("example.txt");
+    fs::create_dir_all(&examples)?;
+
+    fs::write(&module, solution(day))?;
+    fs::write(&bin, binary(day))?;
+    fs::write(&lib_path, lib)?;
+    let mut written = vec![module, bin, lib_path];
+    if !example.exists() {
+        fs::write(&example, "")?;
+        written.push(example);
+    }
+    Ok(written)
+}
+
+/// Entry point for `aoc new <day>`
+pub fn new_main(args: Vec<String>) -> Result<()> {
+    let day = match &args[..] {
+        [day] => day.parse()?,
+        _ => bail!("usage: aoc new <day>"),
+    };
+    let root = input::find_root("src/lib.rs")?
+        .ok_or_else(|| anyhow!("run this from inside the repository"))?;
+
+    for path in generate(&root, day)? {
+        println!("wrote {}", path.display());
+    }
+    println!(
+        "Add an expected answers file next to examples/day{}/example.txt to test it",
+        day
+    );
+    Ok(())
+}
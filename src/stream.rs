@@ -0,0 +1,164 @@
+//! Parsing inputs a line at a time
+//!
+//! Normally the whole input is read into a `String` and normalised before it's parsed. For
+//! generated inputs of hundreds of megabytes that's a lot of copying, so days whose input is one
+//! item per line can also declare a [`LineParser`] (see [`Solution::LINES`]) and be run with
+//! `--stream`. The input is then handed to the parser one line at a time, straight out of a memory
+//! map of the file with the `mmap` feature, or through a buffered reader without it.
+//!
+//! Lines are normalised like whole inputs: a byte order mark is dropped, line endings are stripped
+//! whether they're LF or CRLF, and blank lines at the end are skipped. `--trailing-newline` has no
+//! effect when streaming. Compressed files are decompressed as they're read, rather than mapped.
+//!
+//! [`Solution::LINES`]: crate::Solution::LINES
+
+use std::fs::File;
+use std::io::{self, BufRead};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+
+use crate::cli::Parts;
+use crate::error::Error;
+use crate::{compression, day_label, input, solve_parsed, Params, Report, Result, Solution};
+
+/// How to build a day's input from its lines
+pub struct LineParser<T> {
+    /// The input before any lines have been parsed
+    pub empty: fn() -> T,
+    /// Add a line, without its line ending, to the input parsed so far
+    pub line: fn(&mut T, &str) -> Result<()>,
+}
+
+/// Hands lines to a parser, holding blank lines back until it's clear they aren't at the end
+struct Feeder<'p, T> {
+    parser: &'p LineParser<T>,
+    input: T,
+    blank: Vec<(usize, String)>,
+}
+
+impl<'p, T> Feeder<'p, T> {
+    fn new(parser: &'p LineParser<T>) -> Self {
+        Feeder {
+            parser,
+            input: (parser.empty)(),
+            blank: Vec::new(),
+        }
+    }
+
+    /// Parse line `number`, counting from 1
+    fn feed(&mut self, number: usize, line: &str) -> Result<()> {
+        let mut line = line.strip_suffix('\r').unwrap_or(line);
+        if number == 1 {
+            line = line.strip_prefix('\u{feff}').unwrap_or(line);
+        }
+        if line.trim().is_empty() {
+            self.blank.push((number, line.to_owned()));
+            return Ok(());
+        }
+
+        let parse = self.parser.line;
+        for (number, blank) in self.blank.drain(..) {
+            parse(&mut self.input, &blank).with_context(|| format!("line {}", number))?;
+        }
+        parse(&mut self.input, line).with_context(|| format!("line {}", number))
+    }
+
+    fn feed_str(&mut self, text: &str) -> Result<()> {
+        for (i, line) in text.lines().enumerate() {
+            self.feed(i + 1, line)?;
+        }
+        Ok(())
+    }
+
+    fn feed_reader<R: BufRead>(&mut self, mut reader: R, name: &str) -> Result<()> {
+        let mut line = String::new();
+        for number in 1.. {
+            line.clear();
+            if reader
+                .read_line(&mut line)
+                .map_err(|e| Error::read(name, e))?
+                == 0
+            {
+                break;
+            }
+            self.feed(number, line.strip_suffix('\n').unwrap_or(&line))?;
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "mmap")]
+    fn feed_file(&mut self, path: &str) -> Result<()> {
+        let mut file = File::open(path).map_err(|e| Error::read(path, e))?;
+        if compression::is_compressed(&mut file).map_err(|e| Error::read(path, e))? {
+            let reader = compression::decompress(file).map_err(|e| Error::read(path, e))?;
+            return self.feed_reader(io::BufReader::new(reader), path);
+        }
+        // Safety: the map is only ever read. If the file is truncated while it's being parsed,
+        // reading past the new end faults, which is the usual caveat of mapping files and one
+        // worth accepting for puzzle inputs.
+        let map = unsafe { memmap2::Mmap::map(&file) }.map_err(|e| Error::read(path, e))?;
+        let text = std::str::from_utf8(&map).with_context(|| format!("{} isn't UTF-8", path))?;
+        self.feed_str(text)
+    }
+
+    #[cfg(not(feature = "mmap"))]
+    fn feed_file(&mut self, path: &str) -> Result<()> {
+        let file = File::open(path).map_err(|e| Error::read(path, e))?;
+        let reader = compression::decompress(file).map_err(|e| Error::read(path, e))?;
+        self.feed_reader(io::BufReader::new(reader), path)
+    }
+}
+
+/// Parse the input from `filename` (`-` for stdin), or the default input file for a day, a line
+/// at a time
+///
+/// A default input that has to be downloaded first is read into memory as usual.
+pub fn parse_lines<T>(
+    parser: &LineParser<T>,
+    filename: Option<&str>,
+    year: u64,
+    day: u64,
+) -> Result<T> {
+    let mut feeder = Feeder::new(parser);
+    match filename {
+        Some("-") => {
+            let stdin =
+                compression::decompress(io::stdin()).map_err(|e| Error::read("stdin", e))?;
+            feeder.feed_reader(io::BufReader::new(stdin), "stdin")?
+        }
+        Some(path) => feeder.feed_file(path)?,
+        None => {
+            let path = input::default_file(year, day)?;
+            if path.exists() {
+                feeder.feed_file(&path.display().to_string())?;
+            } else {
+                feeder.feed_str(&input::puzzle_input(None, year, day)?)?;
+            }
+        }
+    }
+    Ok(feeder.input)
+}
+
+/// Like [`crate::solve_within`], reading the input from `filename` a line at a time
+///
+/// The parse time includes reading the input, since the two happen together.
+pub fn solve_lines<S: Solution>(
+    filename: Option<&str>,
+    parts: Parts,
+    params: &Params,
+    budget: Option<Duration>,
+) -> Result<Report> {
+    let parser = S::LINES.ok_or_else(|| {
+        anyhow!(
+            "day {} can't be parsed a line at a time",
+            day_label(S::YEAR, S::DAY)
+        )
+    })?;
+    solve_parsed::<S>(
+        || parse_lines(&parser, filename, S::YEAR, S::DAY),
+        parts,
+        params,
+        budget,
+    )
+}
@@ -0,0 +1,415 @@
+//! Submitting answers to the Advent of Code website
+//!
+//! Every submission is logged in `submissions.txt` (next to the `input/` directory) along with
+//! the server's verdict, so a value that's already known to be wrong is never sent twice. The log
+//! also remembers when the server last asked us to wait, and refuses to submit until then.
+
+use std::env;
+use std::fmt::{self, Display, Write as _};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, bail, ensure};
+
+use crate::cli::Parts;
+use crate::fetch::Client;
+use crate::verify::Answers;
+use crate::{day_label, input, parse_day, parse_day_label, Result};
+
+/// What the server made of a submitted answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh {
+        wait: Duration,
+    },
+    TooLow {
+        wait: Duration,
+    },
+    Wrong {
+        wait: Duration,
+    },
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part has already been solved, or part one hasn't been solved yet
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Interpret the HTML page returned after submitting an answer
+    pub fn parse(html: &str) -> Self {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            let wait = wrong_answer_wait(&text);
+            if text.contains("too high") {
+                Outcome::TooHigh { wait }
+            } else if text.contains("too low") {
+                Outcome::TooLow { wait }
+            } else {
+                Outcome::Wrong { wait }
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited {
+                wait: time_left(&text).unwrap_or(Duration::from_secs(60)),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown(text)
+        }
+    }
+
+    /// How long the server wants us to wait before submitting again
+    pub fn wait(&self) -> Option<Duration> {
+        match *self {
+            Outcome::TooHigh { wait }
+            | Outcome::TooLow { wait }
+            | Outcome::Wrong { wait }
+            | Outcome::RateLimited { wait } => Some(wait),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh { .. } => write!(f, "too high"),
+            Outcome::TooLow { .. } => write!(f, "too low"),
+            Outcome::Wrong { .. } => write!(f, "wrong"),
+            Outcome::RateLimited { wait } => write!(f, "rate limited for {:?}", wait),
+            Outcome::WrongLevel => write!(f, "not the right level (already solved?)"),
+            Outcome::Unknown(text) => write!(f, "unrecognised response: {}", text),
+        }
+    }
+}
+
+/// The text of the `<article>` element, with tags stripped
+fn article_text(html: &str) -> String {
+    let body = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse "You have 1m 23s left to wait"
+fn time_left(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for part in text[start..end].split_whitespace() {
+        let (num, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let num: u64 = num.parse().ok()?;
+        secs += match unit {
+            "h" => num * 3600,
+            "m" => num * 60,
+            "s" => num,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Parse "please wait one minute" or "please wait 5 minutes" after a wrong answer
+fn wrong_answer_wait(text: &str) -> Duration {
+    let minutes = text
+        .find("wait ")
+        .map(|i| &text[i + "wait ".len()..])
+        .and_then(|rest| rest.split_whitespace().next())
+        .and_then(|n| match n {
+            "one" => Some(1),
+            n => n.parse().ok(),
+        })
+        .unwrap_or(1);
+    Duration::from_secs(60 * minutes)
+}
+
+impl Client {
+    /// Post an answer for one part of a day's puzzle
+    pub fn submit_answer(&self, year: u64, day: u64, part: u8, answer: &str) -> Result<Outcome> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url(), year, day);
+        let html = self.post_form(&url, &[("level", &part.to_string()), ("answer", answer)])?;
+        Ok(Outcome::parse(&html))
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// A previous submission and its verdict
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u64,
+    pub day: u64,
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// The parts of an [`Outcome`] worth remembering
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    fn from_outcome(outcome: &Outcome) -> Option<Self> {
+        match outcome {
+            Outcome::Correct => Some(Verdict::Correct),
+            Outcome::TooHigh { .. } => Some(Verdict::TooHigh),
+            Outcome::TooLow { .. } => Some(Verdict::TooLow),
+            Outcome::Wrong { .. } => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => bail!("unknown verdict: {}", s),
+        }
+    }
+}
+
+/// Everything we've submitted so far, and when we're next allowed to submit
+#[derive(Clone, Debug, Default)]
+pub struct SubmissionLog {
+    pub submissions: Vec<Submission>,
+    /// Unix time before which the server will refuse submissions
+    pub cooldown_until: u64,
+}
+
+impl SubmissionLog {
+    pub fn default_path() -> Result<PathBuf> {
+        let root = match input::find_root("submissions.txt")? {
+            Some(root) => root,
+            None => match input::find_root("input")? {
+                Some(root) => root,
+                None => env::current_dir()?,
+            },
+        };
+        Ok(root.join("submissions.txt"))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(SubmissionLog::default());
+        }
+
+        let mut log = SubmissionLog::default();
+        for line in fs::read_to_string(path)?.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<_> = line.splitn(4, ' ').collect();
+            match fields[..] {
+                ["cooldown", until] => log.cooldown_until = until.parse()?,
+                [day, part, verdict, answer] => {
+                    let (year, day) = parse_day_label(day)?;
+                    log.submissions.push(Submission {
+                        year,
+                        day,
+                        part: part.parse()?,
+                        verdict: verdict.parse()?,
+                        answer: answer.to_owned(),
+                    })
+                }
+                _ => bail!("invalid submission log line: {}", line),
+            }
+        }
+        Ok(log)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut out = String::from("# day part verdict answer\n");
+        for s in &self.submissions {
+            ensure!(!s.answer.contains('\n'), "answers must fit on one line");
+            writeln!(
+                out,
+                "{} {} {} {}",
+                day_label(s.year, s.day),
+                s.part,
+                s.verdict.as_str(),
+                s.answer
+            )?;
+        }
+        if self.cooldown_until != 0 {
+            writeln!(out, "cooldown {}", self.cooldown_until)?;
+        }
+        fs::write(path, out)?;
+        Ok(())
+    }
+
+    /// Explain why `answer` shouldn't be submitted, if we already know how it'll go
+    pub fn check(&self, year: u64, day: u64, part: u8, answer: &str, now: u64) -> Option<String> {
+        let previous = self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part);
+        let value: Option<i128> = answer.parse().ok();
+
+        for s in previous {
+            if s.verdict == Verdict::Correct {
+                return Some(format!("already solved with {}", s.answer));
+            }
+            if s.answer == answer {
+                return Some(format!(
+                    "{} was already submitted and was {}",
+                    answer,
+                    s.verdict.as_str().replace('_', " ")
+                ));
+            }
+            if let (Some(value), Ok(bound)) = (value, s.answer.parse::<i128>()) {
+                if s.verdict == Verdict::TooHigh && value >= bound {
+                    return Some(format!("{} was too high, so {} is too", bound, value));
+                }
+                if s.verdict == Verdict::TooLow && value <= bound {
+                    return Some(format!("{} was too low, so {} is too", bound, value));
+                }
+            }
+        }
+
+        if self.cooldown_until > now {
+            return Some(format!(
+                "the server asked us to wait another {}s",
+                self.cooldown_until - now
+            ));
+        }
+        None
+    }
+
+    pub fn record(
+        &mut self,
+        year: u64,
+        day: u64,
+        part: u8,
+        answer: &str,
+        outcome: &Outcome,
+        now: u64,
+    ) {
+        if let Some(verdict) = Verdict::from_outcome(outcome) {
+            self.submissions.push(Submission {
+                year,
+                day,
+                part,
+                verdict,
+                answer: answer.to_owned(),
+            });
+        }
+        if let Some(wait) = outcome.wait() {
+            self.cooldown_until = now + wait.as_secs();
+        }
+    }
+}
+
+/// Check `answer` against the log, submit it, and record the outcome
+pub fn submit(
+    client: &Client,
+    log: &mut SubmissionLog,
+    year: u64,
+    day: u64,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome> {
+    ensure!(part == 1 || part == 2, "invalid part: {}", part);
+    if let Some(reason) = log.check(year, day, part, answer, now()) {
+        bail!("not submitting: {}", reason);
+    }
+
+    let outcome = client.submit_answer(year, day, part, answer)?;
+    log.record(year, day, part, answer, &outcome, now());
+    Ok(outcome)
+}
+
+/// Entry point for `aoc submit <day> <1|2> [answer]`
+///
+/// Without an answer, the day is solved against its default input first.
+pub fn submit_main(args: Vec<String>) -> Result<()> {
+    let usage = || anyhow!("usage: aoc submit <day> <1|2> [answer]");
+    let mut args = args.into_iter();
+    let day = parse_day(&args.next().ok_or_else(usage)?)?;
+    let part: u8 = args.next().ok_or_else(usage)?.parse()?;
+    ensure!(part == 1 || part == 2, "invalid part: {}", part);
+
+    let answer = match args.next() {
+        Some(answer) => answer,
+        None => {
+            let input = input::puzzle_input(None, day.year, day.day)?;
+            let parts = if part == 1 { Parts::One } else { Parts::Two };
+            let report = (day.solve)(&input, parts)?;
+            let answer = report.answer(part).expect("the requested part was solved");
+            answer.answer.clone()
+        }
+    };
+
+    let client = Client::from_env()?.ok_or_else(|| anyhow!("set AOC_SESSION to submit"))?;
+    let path = SubmissionLog::default_path()?;
+    let mut log = SubmissionLog::load(&path)?;
+
+    let result = submit(&client, &mut log, day.year, day.day, part, &answer);
+    log.save(&path)?;
+    let outcome = result?;
+    println!(
+        "Day {} part {}: {} is {}",
+        day.label(),
+        part,
+        answer,
+        outcome
+    );
+
+    if outcome == Outcome::Correct {
+        let mut answers = Answers::load(day.year, day.day)?.unwrap_or_default();
+        if part == 1 {
+            answers.part_one = Some(answer);
+        } else {
+            answers.part_two = Some(answer);
+        }
+        answers.save(day.year, day.day)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,175 @@
+//! Checking solutions against known-correct answers
+//!
+//! Answers live in `answers/YEAR/dayN.txt` (or `answers/dayN.txt`) next to the `input/`
+//! directory. The first line is the answer to part one and the second line the answer to part two.
+//! A blank line means the answer isn't known yet.
+
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, ensure};
+
+use crate::cli::Parts;
+use crate::{input, parse_day, Day, Report, Result, DAYS};
+
+fn answers_path(year: u64, day: u64) -> String {
+    input::year_path("answers", year, day, ".txt")
+}
+
+/// Known-correct answers for a single day
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Self {
+        let mut lines = s.lines().map(str::trim);
+        let mut next = || lines.next().filter(|l| !l.is_empty()).map(str::to_owned);
+        let part_one = next();
+        let part_two = next();
+        Answers { part_one, part_two }
+    }
+
+    /// Load the answers for a day, if an answers file exists
+    pub fn load(year: u64, day: u64) -> Result<Option<Self>> {
+        let subpath = answers_path(year, day);
+        match input::find_root(&subpath)? {
+            Some(root) => Ok(Some(Answers::parse(&fs::read_to_string(
+                root.join(subpath),
+            )?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Write the answers for a day next to its input file
+    pub fn save(&self, year: u64, day: u64) -> Result<PathBuf> {
+        for answer in [&self.part_one, &self.part_two].into_iter().flatten() {
+            ensure!(!answer.contains('\n'), "answers must fit on one line");
+        }
+        let root = input::find_root(input::default_path(year, day))?
+            .ok_or_else(|| anyhow!("no input file found"))?;
+        let path = root.join(answers_path(year, day));
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let line = |a: &Option<String>| a.clone().unwrap_or_default();
+        fs::write(
+            &path,
+            format!("{}\n{}\n", line(&self.part_one), line(&self.part_two)),
+        )?;
+        Ok(path)
+    }
+}
+
+impl From<&Report> for Answers {
+    fn from(report: &Report) -> Self {
+        Answers {
+            part_one: report.answer(1).map(|p| p.answer.clone()),
+            part_two: report.answer(2).map(|p| p.answer.clone()),
+        }
+    }
+}
+
+/// Outcome of checking a single part against its expected answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&str>, actual: Option<&str>) -> Self {
+        match (expected, actual) {
+            (Some(e), Some(a)) if e == a => Verdict::Pass,
+            (Some(e), Some(a)) => Verdict::Fail {
+                expected: e.to_owned(),
+                actual: a.to_owned(),
+            },
+            _ => Verdict::Unknown,
+        }
+    }
+
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::Fail { .. })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {}, got {})", expected, actual)
+            }
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Run a day against its default input and compare with the stored answers
+pub fn verify_day(day: &Day) -> Result<(Report, [Verdict; 2])> {
+    let input = input::puzzle_input(None, day.year, day.day)?;
+    let report = (day.solve)(&input, Parts::Both)?;
+    let answers = Answers::load(day.year, day.day)?.unwrap_or_default();
+    let actual = Answers::from(&report);
+
+    let verdicts = [
+        Verdict::check(answers.part_one.as_deref(), actual.part_one.as_deref()),
+        Verdict::check(answers.part_two.as_deref(), actual.part_two.as_deref()),
+    ];
+    Ok((report, verdicts))
+}
+
+/// Entry point for `aoc verify [day...] [--record]`
+///
+/// With `--record`, the current answers are written to the answers files instead of being
+/// checked.
+pub fn verify_main(args: Vec<String>) -> Result<()> {
+    let mut record = false;
+    let mut days = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--record" => record = true,
+            day => days.push(parse_day(day)?),
+        }
+    }
+    if days.is_empty() {
+        days = DAYS.iter().collect();
+    }
+
+    let mut failures = 0;
+    for day in days {
+        if record {
+            let input = input::puzzle_input(None, day.year, day.day)?;
+            let report = (day.solve)(&input, Parts::Both)?;
+            let path = Answers::from(&report).save(day.year, day.day)?;
+            println!(
+                "Day {}: recorded answers in {}",
+                day.label(),
+                path.display()
+            );
+            continue;
+        }
+
+        match verify_day(day) {
+            Ok((_, [one, two])) => {
+                failures += usize::from(one.is_fail()) + usize::from(two.is_fail());
+                println!("Day {}: part 1 {}, part 2 {}", day.label(), one, two);
+            }
+            Err(e) => {
+                failures += 1;
+                println!("Day {}: error: {}", day.label(), e);
+            }
+        }
+    }
+
+    if failures > 0 {
+        bail!("{} check(s) failed", failures);
+    }
+    Ok(())
+}
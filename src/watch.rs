@@ -0,0 +1,73 @@
+//! Re-running a day whenever its input files change
+//!
+//! Files are polled rather than watched with OS notifications: inputs are small, a few files at
+//! most are watched at once, and polling works the same everywhere.
+
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often to check the watched files for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// What a file looked like when it was last checked, or `None` if it didn't exist
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &PathBuf) -> Stamp {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+/// A set of files, and when each was last seen to change
+#[derive(Debug)]
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    stamps: Vec<Stamp>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let stamps = paths.iter().map(stamp).collect();
+        Watcher { paths, stamps }
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// The files that have been modified, created or deleted since the last poll
+    pub fn poll(&mut self) -> Vec<&PathBuf> {
+        let mut changed = Vec::new();
+        for (path, old) in self.paths.iter().zip(self.stamps.iter_mut()) {
+            let new = stamp(path);
+            if new != *old {
+                *old = new;
+                changed.push(path);
+            }
+        }
+        changed
+    }
+
+    /// Call `f` now, and again every time one of the files changes
+    ///
+    /// This never returns.
+    pub fn run<F: FnMut()>(&mut self, mut f: F) -> ! {
+        f();
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let changed = self.poll();
+            if changed.is_empty() {
+                continue;
+            }
+
+            let names: Vec<_> = changed.iter().map(|p| p.display().to_string()).collect();
+            println!("\n== {} changed ==", names.join(", "));
+
+            // Editors often save in several steps, so let the writes settle before re-running
+            thread::sleep(POLL_INTERVAL);
+            self.poll();
+            f();
+        }
+    }
+}
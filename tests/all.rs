@@ -0,0 +1,62 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn runs_every_requested_day() {
+    let dir = common::temp_dir("all");
+    fs::create_dir(dir.join("input")).unwrap();
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    for day in [1, 2, 6] {
+        fs::copy(
+            examples.join(format!("day{}/example.txt", day)),
+            dir.join(format!("input/day{}.txt", day)),
+        )
+        .unwrap();
+    }
+
+    let out = common::run_aoc(&dir, &["all", "1", "2", "6", "-j", "2"]);
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    let rows: Vec<Vec<&str>> = stdout
+        .lines()
+        .skip(1)
+        .take(3)
+        .map(|l| l.split_whitespace().take(3).collect())
+        .collect();
+    assert_eq!(
+        rows,
+        [
+            ["1", "7", "5"],
+            ["2", "150", "900"],
+            ["6", "5934", "26984457539"]
+        ]
+    );
+
+    // Day 3 has no input, so it's reported without stopping the others
+    let out = common::run_aoc(&dir, &["all", "1", "3", "--format", "json"]);
+    assert_eq!(out.status.code(), Some(2));
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 3);
+    assert!(stdout.lines().last().unwrap().contains("\"error\""));
+}
+
+#[test]
+fn all_gives_up_on_runaway_parts() {
+    let dir = common::temp_dir("all-timeout");
+    fs::create_dir(dir.join("input")).unwrap();
+    // Two big caves next to each other make infinitely many paths
+    fs::write(dir.join("input/day12.txt"), "start-A\nA-B\nB-end\n").unwrap();
+
+    let out = common::run_aoc(&dir, &["all", "12", "--timeout", "200ms"]);
+    assert_eq!(out.status.code(), Some(6));
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    let row: Vec<_> = stdout.lines().nth(1).unwrap().split_whitespace().collect();
+    assert_eq!(
+        row[..5],
+        ["12", "timed", "out", "timed", "out"],
+        "{}",
+        stdout
+    );
+}
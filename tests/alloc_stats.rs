@@ -0,0 +1,51 @@
+use aoc::alloc_stats::measure;
+
+#[cfg(feature = "alloc-stats")]
+#[test]
+fn counts_allocations_in_a_phase() {
+    let (v, stats) = measure(|| {
+        let mut v: Vec<u64> = Vec::with_capacity(100);
+        v.push(1);
+        let scratch = vec![0u8; 4096];
+        drop(scratch);
+        v
+    });
+    let stats = stats.unwrap();
+    assert_eq!(v, [1]);
+    assert!(stats.allocations >= 2, "{:?}", stats);
+    assert!(stats.bytes >= 800 + 4096, "{:?}", stats);
+    assert!(stats.peak_bytes >= 800 + 4096, "{:?}", stats);
+}
+
+#[cfg(feature = "alloc-stats")]
+#[test]
+fn other_threads_are_not_counted() {
+    use std::sync::Barrier;
+    use std::thread;
+
+    const BIG: usize = 1 << 20;
+    let barrier = Barrier::new(2);
+    thread::scope(|s| {
+        s.spawn(|| {
+            barrier.wait();
+            let big = vec![1u8; BIG];
+            barrier.wait();
+            drop(big);
+        });
+        let ((), stats) = measure(|| {
+            barrier.wait();
+            barrier.wait();
+        });
+        let stats = stats.unwrap();
+        assert!(stats.bytes < BIG as u64, "{:?}", stats);
+        assert!(stats.peak_bytes < BIG as u64, "{:?}", stats);
+    });
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+#[test]
+fn nothing_is_counted_without_the_feature() {
+    let (v, stats) = measure(|| vec![1, 2, 3]);
+    assert_eq!(v, [1, 2, 3]);
+    assert!(stats.is_none());
+}
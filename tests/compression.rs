@@ -0,0 +1,132 @@
+#![cfg(feature = "compression")]
+
+mod common;
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use flate2::write::GzEncoder;
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn example() -> Vec<u8> {
+    fs::read(Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/day1/example.txt")).unwrap()
+}
+
+/// The answers printed by `aoc run`, without the timings or any allocation counts
+fn answers(stdout: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(stdout)
+        .lines()
+        .filter(|l| l.starts_with("Part ") && l.contains(" Count: "))
+        .map(|l| l.split(" (").next().unwrap().to_owned())
+        .collect()
+}
+
+const EXPECTED: [&str; 2] = ["Part 1 Count: 7", "Part 2 Count: 5"];
+
+#[test]
+fn compressed_files_are_decompressed() {
+    let dir = common::temp_dir("compression");
+    fs::write(dir.join("depths.gz"), gzip(&example())).unwrap();
+    fs::write(
+        dir.join("depths"),
+        zstd::encode_all(&example()[..], 0).unwrap(),
+    )
+    .unwrap();
+
+    for file in ["depths.gz", "depths"] {
+        let out = common::run_aoc(&dir, &["run", "1", file]);
+        assert!(out.status.success(), "{:?}", out);
+        assert_eq!(answers(&out.stdout), EXPECTED);
+
+        let out = common::run_aoc(&dir, &["run", "1", "--stream", file]);
+        assert!(out.status.success(), "{:?}", out);
+        assert_eq!(answers(&out.stdout), EXPECTED);
+    }
+
+    fs::write(dir.join("broken.gz"), &gzip(&example())[..20]).unwrap();
+    let out = common::run_aoc(&dir, &["run", "1", "broken.gz"]);
+    assert_eq!(out.status.code(), Some(3), "{:?}", out);
+}
+
+#[test]
+fn compressed_default_inputs_are_found() {
+    let dir = common::temp_dir("compression-default");
+    fs::create_dir(dir.join("input")).unwrap();
+    fs::write(
+        dir.join("input/day1.txt.zst"),
+        zstd::encode_all(&example()[..], 0).unwrap(),
+    )
+    .unwrap();
+
+    let out = common::run_aoc(&dir, &["run", "1"]);
+    assert!(out.status.success(), "{:?}", out);
+    assert_eq!(answers(&out.stdout), EXPECTED);
+}
+
+#[test]
+fn compressed_stdin_is_decompressed() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "1", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(&gzip(&example()))
+        .unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert!(out.status.success(), "{:?}", out);
+    assert_eq!(answers(&out.stdout), EXPECTED);
+}
+
+#[test]
+fn watch_mode_watches_compressed_default_inputs() {
+    let dir = common::temp_dir("compression-watch");
+    fs::create_dir(dir.join("input")).unwrap();
+    let input = dir.join("input/day1.txt.gz");
+    fs::write(&input, gzip(&example())).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "1", "--watch"])
+        .current_dir(&dir)
+        .env("AOC_SESSION", "")
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let (tx, rx) = mpsc::channel();
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    thread::spawn(move || {
+        for line in stdout.lines() {
+            let _ = tx.send(line.unwrap());
+        }
+    });
+    let next_line = || rx.recv_timeout(Duration::from_secs(10));
+
+    while !next_line().unwrap().starts_with("Part 2 Count: 5 ") {}
+    fs::write(&input, gzip(b"1\n2\n3\n4\n")).unwrap();
+    let changed = loop {
+        match next_line() {
+            Ok(line) if line.contains("changed ==") => break Some(line),
+            Ok(_) => {}
+            Err(_) => break None,
+        }
+    };
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    let changed = changed.expect("the compressed input wasn't watched");
+    assert!(changed.contains("day1.txt.gz"), "{}", changed);
+}
@@ -0,0 +1,61 @@
+use std::fs;
+
+use aoc::crosscheck::{find_reference, PartCheck, REFERENCES};
+use aoc::generate::{find_generator, Rng};
+
+fn overrides(day: u64) -> Vec<(String, String)> {
+    // Small enough for the brute-force references to finish
+    let param = |name: &str, value: &str| vec![(name.to_owned(), value.to_owned())];
+    match day {
+        6 => param("part2_days", "100"),
+        14 => param("part2_steps", "12"),
+        _ => Vec::new(),
+    }
+}
+
+#[test]
+fn references_agree_on_the_examples() {
+    for checked in REFERENCES {
+        let path = format!(
+            "{}/examples/day{}/example.txt",
+            env!("CARGO_MANIFEST_DIR"),
+            checked.day
+        );
+        let input = fs::read_to_string(path).unwrap();
+        for part in (checked.check)(&input, &overrides(checked.day)).unwrap() {
+            assert!(part.reference.is_ok(), "day {} {}", checked.day, part);
+            assert!(!part.disagrees(), "day {} {}", checked.day, part);
+        }
+    }
+}
+
+#[test]
+fn references_agree_on_generated_inputs() {
+    for checked in REFERENCES {
+        let generator = find_generator(checked.year, checked.day).unwrap();
+        for seed in 0..10 {
+            let input = (generator.generate)(&mut Rng::new(seed), 10);
+            let parts = (checked.check)(&input, &overrides(checked.day)).unwrap();
+            assert!(
+                !parts.iter().any(PartCheck::disagrees),
+                "day {} seed {}:\n{}\n{}\n{}",
+                checked.day,
+                seed,
+                input,
+                parts[0],
+                parts[1]
+            );
+        }
+    }
+}
+
+#[test]
+fn unsolvable_references_are_unchecked() {
+    let checked = find_reference(2021, 6).unwrap();
+    let [one, two] = (checked.check)("3,4,3,1,2", &[]).unwrap();
+    assert!(one.reference.is_ok() && !one.disagrees());
+    assert!(two.reference.is_err() && !two.disagrees());
+    assert!(two.to_string().contains("unchecked"), "{}", two);
+
+    assert!(find_reference(2021, 1).is_err());
+}
@@ -0,0 +1,32 @@
+//! One test per `examples/dayN/*.txt` (or `examples/YEAR/dayN/*.txt`) file, generated by `build.rs`
+
+use std::fs;
+use std::path::Path;
+
+use aoc::cli::Parts;
+use aoc::find_day;
+use aoc::normalize::{normalize, TrailingNewline};
+use aoc::verify::{Answers, Verdict};
+
+fn check_example(year: u64, day: u64, path: &str) {
+    let input = normalize(
+        &fs::read_to_string(path).unwrap(),
+        TrailingNewline::default(),
+    );
+    let expected =
+        Answers::parse(&fs::read_to_string(Path::new(path).with_extension("expected")).unwrap());
+
+    let day = find_day(year, day).unwrap();
+    let report = (day.solve)(&input, Parts::Both).unwrap();
+    let actual = Answers::from(&report);
+
+    for (part, expected, actual) in [
+        (1, &expected.part_one, &actual.part_one),
+        (2, &expected.part_two, &actual.part_two),
+    ] {
+        let verdict = Verdict::check(expected.as_deref(), actual.as_deref());
+        assert!(!verdict.is_fail(), "{} part {}: {}", path, part, verdict);
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
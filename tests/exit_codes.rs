@@ -0,0 +1,149 @@
+mod common;
+
+use std::fs;
+
+fn aoc(args: &[&str]) -> (i32, String) {
+    let out = common::run_aoc(&common::temp_dir("exit-codes"), args);
+    (
+        out.status.code().unwrap(),
+        String::from_utf8(out.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn exit_codes_distinguish_error_kinds() {
+    let dir = common::temp_dir("exit-code-inputs");
+    let bad = dir.join("bad.txt");
+    fs::write(&bad, "0,9 -> 5,9\n8,0 => 0,8\n").unwrap();
+    let bad = bad.to_str().unwrap();
+
+    let (code, stderr) = aoc(&["run", "5"]);
+    assert_eq!(code, 2, "{}", stderr);
+    assert!(
+        stderr.starts_with("error: no input file found"),
+        "{}",
+        stderr
+    );
+
+    let (code, _) = aoc(&["run", "5", "missing.txt"]);
+    assert_eq!(code, 2);
+
+    let (code, _) = aoc(&["run", "5", dir.to_str().unwrap()]);
+    assert_eq!(code, 3);
+
+    let (code, stderr) = aoc(&["run", "5", bad]);
+    assert_eq!(code, 4, "{}", stderr);
+    assert!(!stderr.contains("panicked"), "{}", stderr);
+
+    let (code, _) = aoc(&["frobnicate"]);
+    assert_eq!(code, 1);
+}
+
+#[test]
+fn solve_errors_are_tagged() {
+    let day = aoc::find_day(2021, 16).unwrap();
+    // Parses as hex, but isn't a valid transmission
+    let err = (day.solve)("00", aoc::cli::Parts::Both).unwrap_err();
+    assert_eq!(aoc::error::exit_code_of(&err), 5);
+}
+
+#[test]
+fn malformed_numbers_are_parse_errors() {
+    for (day, input) in [
+        (6, "3,4,9"),
+        (11, "3x\n12\n"),
+        (15, "3x\n12\n"),
+        (15, "10\n11\n"),
+    ] {
+        let day = aoc::find_day(2021, day).unwrap();
+        let err = (day.solve)(input, aoc::cli::Parts::Both).unwrap_err();
+        assert_eq!(
+            aoc::error::exit_code_of(&err),
+            4,
+            "day {}: {:#}",
+            day.day,
+            err
+        );
+    }
+}
+
+#[test]
+fn malformed_heightmaps_are_parse_errors() {
+    let day = aoc::find_day(2021, 9).unwrap();
+    for input in ["12\n3\n", "12\n3 \n"] {
+        let err = (day.solve)(input, aoc::cli::Parts::Both).unwrap_err();
+        assert_eq!(aoc::error::exit_code_of(&err), 4, "{:?}", input);
+    }
+}
+
+#[test]
+fn octopuses_that_never_synchronise_are_an_error() {
+    let day = aoc::find_day(2021, 11).unwrap();
+    // Settles into a cycle in which some octopuses never flash with the rest
+    let err = (day.solve)("29141\n77763\n17066\n90743\n", aoc::cli::Parts::Two).unwrap_err();
+    assert_eq!(aoc::error::exit_code_of(&err), 5);
+    assert!(
+        format!("{:#}", err).contains("never all flash"),
+        "{:#}",
+        err
+    );
+}
+
+#[test]
+fn runaway_parts_time_out() {
+    let dir = common::temp_dir("exit-code-timeout");
+    // Two big caves next to each other make infinitely many paths
+    let caves = dir.join("caves.txt");
+    fs::write(&caves, "start-A\nA-B\nB-end\n").unwrap();
+    let caves = caves.to_str().unwrap();
+
+    let (code, stderr) = aoc(&["run", "12", caves, "--timeout", "200ms"]);
+    assert_eq!(code, 6, "{}", stderr);
+    assert!(
+        stderr.contains("part 1 timed out after 200.00ms"),
+        "{}",
+        stderr
+    );
+
+    let (code, stderr) = aoc(&["run", "12", "--timeout", "soon"]);
+    assert_eq!(code, 1, "{}", stderr);
+
+    assert_eq!(
+        aoc::cli::parse_duration("1.5").unwrap(),
+        std::time::Duration::from_millis(1500)
+    );
+    assert!(aoc::cli::parse_duration("1000000000000000000000000m").is_err());
+}
+
+#[test]
+fn failed_parts_keep_the_other_answer() {
+    let dir = common::temp_dir("exit-code-partial");
+    let octopuses = dir.join("octopuses.txt");
+    fs::write(&octopuses, "29141\n77763\n17066\n90743\n").unwrap();
+    let octopuses = octopuses.to_str().unwrap();
+
+    let day = aoc::find_day(2021, 11).unwrap();
+    let report =
+        (day.solve_within)("29141\n77763\n17066\n90743\n", aoc::cli::Parts::Both, None).unwrap();
+    assert!(report.answer(1).is_some());
+    assert_eq!(aoc::error::exit_code_of(&report.failure().unwrap()), 5);
+
+    let out = common::run_aoc(&dir, &["run", "11", octopuses, "--format", "json"]);
+    assert_eq!(out.status.code(), Some(5));
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "{}", stdout);
+    assert!(lines[0].contains("\"part\":1,\"answer\":"), "{}", stdout);
+    assert!(
+        lines[1].contains("\"part\":2,\"error\":\"part 2 failed: ")
+            && lines[1].contains("never all flash"),
+        "{}",
+        stdout
+    );
+
+    let out = common::run_aoc(&dir, &["run", "11", octopuses]);
+    assert_eq!(out.status.code(), Some(5));
+    assert!(String::from_utf8(out.stdout)
+        .unwrap()
+        .contains("Part 1 Count: "));
+}
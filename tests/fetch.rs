@@ -0,0 +1,39 @@
+#![cfg(feature = "fetch")]
+
+mod common;
+
+use std::fs;
+
+use aoc::fetch::Client;
+
+#[test]
+fn downloads_and_caches_input() {
+    let (base_url, requests) = common::serve(vec![(200, "1\n2\n3\n")]);
+    let dir = common::temp_dir("fetch");
+    let path = dir.join("input").join("day1.txt");
+
+    let client = Client::new(base_url, "s3cret");
+    assert_eq!(client.cached_input(2021, 1, &path).unwrap(), "1\n2\n3\n");
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+
+    let req = requests.recv().unwrap();
+    assert_eq!(req.method, "GET");
+    assert_eq!(req.path, "/2021/day/1/input");
+    assert_eq!(req.header("cookie"), Some("session=s3cret"));
+    assert!(req.body.is_empty());
+
+    // The stub only answers once, so this has to come from the cache
+    assert_eq!(client.cached_input(2021, 1, &path).unwrap(), "1\n2\n3\n");
+}
+
+#[test]
+fn errors_are_not_cached() {
+    let (base_url, _requests) = common::serve(vec![(400, "Please log in")]);
+    let dir = common::temp_dir("fetch-error");
+    let path = dir.join("input").join("day2.txt");
+
+    let client = Client::new(base_url, "expired");
+    let err = client.cached_input(2021, 2, &path).unwrap_err();
+    assert!(err.to_string().contains("400"), "{}", err);
+    assert!(!path.exists());
+}
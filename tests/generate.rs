@@ -0,0 +1,55 @@
+mod common;
+
+use aoc::cli::Parts;
+use aoc::find_day;
+use aoc::generate::{find_generator, Rng, GENERATORS};
+
+#[test]
+fn seeds_are_reproducible() {
+    let generator = find_generator(2021, 18).unwrap();
+    let input = |seed| (generator.generate)(&mut Rng::new(seed), 20);
+    assert_eq!(input(7), input(7));
+    assert_ne!(input(7), input(8));
+    assert!(find_generator(2021, 19).is_err());
+}
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for generator in GENERATORS {
+        let day = find_day(generator.year, generator.day).unwrap();
+        for seed in 0..5 {
+            let input = (generator.generate)(&mut Rng::new(seed), 10);
+            let context = format!("day {} seed {}:\n{}", day.day, seed, input);
+            (day.solve)(&input, Parts::Both).expect(&context);
+        }
+    }
+}
+
+#[test]
+fn big_octopus_grids_synchronise() {
+    let generator = find_generator(2021, 11).unwrap();
+    let day = find_day(2021, 11).unwrap();
+    for seed in 0..3 {
+        let input = (generator.generate)(&mut Rng::new(seed), 60);
+        assert_eq!(input.lines().count(), 60);
+        (day.solve)(&input, Parts::Two).expect(&input);
+    }
+}
+
+#[test]
+fn sizes_that_are_too_small_are_errors() {
+    let dir = common::temp_dir("generate-size");
+    let out = common::run_aoc(&dir, &["generate", "17", "--size", "1"]);
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert_eq!(out.status.code(), Some(1), "{}", stderr);
+    assert!(stderr.contains("must be at least 2"), "{}", stderr);
+
+    let out = common::run_aoc(
+        &dir,
+        &["crosscheck", "17", "--generate", "0", "--size", "0"],
+    );
+    assert_eq!(out.status.code(), Some(1));
+
+    let out = common::run_aoc(&dir, &["generate", "17", "--size", "2"]);
+    assert!(out.status.success());
+}
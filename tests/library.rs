@@ -0,0 +1,41 @@
+use aoc::day16::{Packet, PacketData};
+use aoc::day18::SnailfishNumber;
+use aoc::day9::Grid;
+
+#[test]
+fn packets() {
+    let packet: Packet = "D2FE28".parse().unwrap();
+    assert_eq!(packet.version, 6);
+    assert!(matches!(packet.data, PacketData::Literal { .. }));
+    assert_eq!(packet.value(), 2021);
+
+    let packet: Packet = "9C0141080250320F1802104A08".parse().unwrap();
+    assert_eq!(packet.value(), 1);
+    assert!("9C01ZZ".parse::<Packet>().is_err());
+}
+
+#[test]
+fn snailfish_numbers() {
+    let a: SnailfishNumber = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse().unwrap();
+    let b: SnailfishNumber = "[1,1]".parse().unwrap();
+    let sum = a + b;
+    assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+    assert_eq!(sum.magnitude(), 1384);
+    assert!("[1,".parse::<SnailfishNumber>().is_err());
+}
+
+#[test]
+fn grids() {
+    let mut grid = Grid::with_width(3, vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(1, 0), Some(4));
+    assert_eq!(grid.get(2, 0), None);
+    *grid.get_mut(0, 0).unwrap() = 9;
+
+    let mut neighbors: Vec<_> = grid.neighbors(0, 1).collect();
+    neighbors.sort_unstable();
+    assert_eq!(neighbors, [3, 5, 9]);
+
+    assert!(Grid::try_with_width(4, vec![1, 2, 3, 4, 5, 6]).is_err());
+    assert!(Grid::<u8>::try_with_width(0, Vec::new()).is_err());
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use aoc::cli::Parts;
+use aoc::find_day;
+use aoc::normalize::{normalize, TrailingNewline};
+use aoc::verify::Answers;
+
+#[test]
+fn normalises_line_endings_and_trailing_lines() {
+    let input = "\u{feff}1\r\n2 \r\n\r\n  \n";
+    assert_eq!(normalize(input, TrailingNewline::One), "1\n2 \n");
+    assert_eq!(normalize(input, TrailingNewline::None), "1\n2 ");
+    assert_eq!(normalize(input, TrailingNewline::Keep), "1\n2 \n\n  \n");
+    assert_eq!(normalize("1", TrailingNewline::One), "1\n");
+    assert_eq!(normalize("\n\n", TrailingNewline::One), "");
+}
+
+#[test]
+fn every_day_accepts_windows_style_examples() {
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    for day in aoc::DAYS {
+        let path = examples.join(format!("day{}/example.txt", day.day));
+        let unix = fs::read_to_string(&path).unwrap();
+        let windows = format!("\u{feff}{}\r\n\r\n", unix.trim_end().replace('\n', "\r\n"));
+
+        let solve = |input: &str| {
+            let input = normalize(input, TrailingNewline::default());
+            let report = (find_day(day.year, day.day).unwrap().solve)(&input, Parts::Both).unwrap();
+            Answers::from(&report)
+        };
+        assert_eq!(solve(&windows), solve(&unix), "day {}", day.day);
+    }
+}
+
+#[test]
+fn inputs_are_normalised_when_read() {
+    let dir = common::temp_dir("normalize");
+    let path = dir.join("day5.txt");
+    fs::write(
+        &path,
+        "\u{feff}0,9 -> 5,9\r\n8,0 -> 0,8\r\n0,9 -> 2,9\r\n\r\n",
+    )
+    .unwrap();
+
+    let out = common::run_aoc(&dir, &["run", "5", "--part", "1", path.to_str().unwrap()]);
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(stdout.contains("Part 1 Count: 3 "), "{}", stdout);
+}
+
+#[test]
+fn every_example_solves_in_every_trailing_newline_mode() {
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    for day in aoc::DAYS {
+        for entry in fs::read_dir(examples.join(format!("day{}", day.day))).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|ext| ext != "txt") {
+                continue;
+            }
+            let example = fs::read_to_string(&path).unwrap();
+
+            let solve = |trailing| {
+                let input = normalize(&example, trailing);
+                let report = (day.solve)(&input, Parts::Both)
+                    .unwrap_or_else(|e| panic!("{} with {:?}: {:#}", path.display(), trailing, e));
+                Answers::from(&report)
+            };
+            let expected = solve(TrailingNewline::One);
+            for trailing in [TrailingNewline::None, TrailingNewline::Keep] {
+                assert_eq!(
+                    solve(trailing),
+                    expected,
+                    "{} with {:?}",
+                    path.display(),
+                    trailing
+                );
+            }
+        }
+    }
+}
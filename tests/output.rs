@@ -0,0 +1,33 @@
+mod common;
+
+use std::path::Path;
+
+#[test]
+fn pictures_are_shown_under_their_answer() {
+    let dir = common::temp_dir("output-picture");
+    let example = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/day13/example.txt");
+
+    let out = common::run_aoc(&dir, &["run", "13", example.to_str().unwrap()]);
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(
+        stdout.contains("\n#####\n#...#\n#...#\n#...#\n#####\n"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Part 2 Count: 16 ("), "{}", stdout);
+
+    // Answers stay on one line, and JSON has no room for the picture
+    let out = common::run_aoc(
+        &dir,
+        &["run", "13", "--format", "json", example.to_str().unwrap()],
+    );
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "{}", stdout);
+    assert!(lines
+        .iter()
+        .all(|l| l.starts_with("{\"year\":2021,\"day\":13,") && l.ends_with('}')));
+    assert!(lines[1].contains("\"answer\":\"16\""), "{}", lines[1]);
+}
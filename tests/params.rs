@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::Path;
+
+use aoc::cli::Parts;
+use aoc::day11::Day11;
+use aoc::day14::Day14;
+use aoc::day15::Day15;
+use aoc::day21::Day21;
+use aoc::day5::Day5;
+use aoc::day6::Day6;
+use aoc::{solve_with, Params, Solution};
+
+fn example(day: u64) -> String {
+    let path =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("examples/day{}/example.txt", day));
+    fs::read_to_string(path).unwrap()
+}
+
+fn params<S: Solution>(overrides: &[(&str, &str)]) -> Params {
+    let overrides: Vec<_> = overrides
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    Params::new(S::PARAMS, &overrides).unwrap()
+}
+
+fn part_one<S: Solution>(overrides: &[(&str, &str)]) -> String {
+    let report = solve_with::<S>(&example(S::DAY), Parts::One, &params::<S>(overrides)).unwrap();
+    report.answer(1).unwrap().answer.clone()
+}
+
+#[test]
+fn overrides_replace_defaults() {
+    // Values from the puzzle descriptions
+    assert_eq!(part_one::<Day6>(&[("part1_days", "18")]), "26");
+    assert_eq!(part_one::<Day11>(&[("steps", "10")]), "204");
+    assert_eq!(part_one::<Day14>(&[]), "1588");
+    assert_eq!(part_one::<Day14>(&[("part1_steps", "0")]), "1");
+}
+
+#[test]
+fn unknown_and_invalid_params_are_errors() {
+    let overrides = [("nope".to_owned(), "1".to_owned())];
+    assert!(Params::new(Day6::PARAMS, &overrides).is_err());
+
+    let params = params::<Day6>(&[("part1_days", "lots")]);
+    assert!(params.get::<usize>("part1_days").is_err());
+    assert_eq!(params.get::<usize>("part2_days").unwrap(), 256);
+}
+
+fn solve_both<S: Solution>(overrides: &[(&str, &str)]) -> aoc::Result<aoc::Report> {
+    solve_with::<S>(&example(S::DAY), Parts::Both, &params::<S>(overrides))
+}
+
+#[test]
+fn out_of_range_overrides_are_errors() {
+    assert!(solve_both::<Day5>(&[("grid_size", "100000")]).is_err());
+    assert!(solve_both::<Day6>(&[("part2_days", "1000")]).is_err());
+    assert!(solve_both::<Day14>(&[("part2_steps", "70")]).is_err());
+    assert!(solve_both::<Day15>(&[("tiles", "100000000000")]).is_err());
+    assert!(solve_both::<Day21>(&[("part1_target", "70000")]).is_err());
+    assert!(solve_both::<Day21>(&[("part2_target", "70000")]).is_err());
+
+    // The largest values that are still accepted
+    assert!(solve_both::<Day21>(&[("part1_target", "50000"), ("part2_target", "27")]).is_ok());
+
+    // Tiles more than 255 away from the original still wrap their risk levels from 9 back to 1
+    let report = solve_with::<Day15>("8\n", Parts::Two, &params::<Day15>(&[("tiles", "257")]));
+    assert_eq!(report.unwrap().answer(2).unwrap().answer, "2557");
+}
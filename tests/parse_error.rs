@@ -0,0 +1,70 @@
+use aoc::cli::Parts;
+use aoc::find_day;
+use aoc::parse_error::ParseError;
+
+#[test]
+fn locates_error_in_input() {
+    let input = "0,9 -> 5,9\n8,0 => 0,8\n";
+    let err = ParseError::at(input, &input[15..], "expected tag");
+    assert_eq!(err.line, 2);
+    assert_eq!(err.column, 5);
+    assert_eq!(err.source_line, "8,0 => 0,8");
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 5: expected tag\n  |\n2 | 8,0 => 0,8\n  |     ^"
+    );
+}
+
+#[test]
+fn handles_crlf_and_bytes() {
+    let input = "1,2\r\n3;4\r\n";
+    let err = ParseError::at(input, &input.as_bytes()[6..], "expected tag");
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!(err.source_line, "3;4");
+}
+
+#[test]
+fn trailing_whitespace_is_allowed() {
+    let input = "abc\n\n";
+    assert!(ParseError::check_trailing(input, &input[3..]).is_ok());
+
+    let input = "abc\n\nxyz";
+    let err = ParseError::check_trailing(input, &input[3..]).unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+}
+
+#[test]
+fn days_report_position_of_bad_line() {
+    let day = find_day(2021, 5).unwrap();
+    let err = (day.solve)("0,9 -> 5,9\n8,0 => 0,8\n", Parts::Both).unwrap_err();
+    let err = err
+        .chain()
+        .find_map(|e| e.downcast_ref::<ParseError>())
+        .unwrap();
+    assert_eq!((err.line, err.column), (2, 4));
+}
+
+#[test]
+fn transmissions_report_where_decoding_stopped() {
+    let err = aoc::day16::Packet::decode(&[0xD2, 0xFE]).unwrap_err();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+    assert_eq!((err.line, err.column), (1, 5));
+    assert_eq!(err.source_line, "D2FE");
+    assert!(err.message.contains("partway through a packet"));
+
+    // A less-than packet with only one operand
+    let err = "38002A40".parse::<aoc::day16::Packet>().unwrap_err();
+    assert!(format!("{:#}", err).contains("wrong number of subpackets"));
+}
+
+#[test]
+fn dirac_dice_report_position_of_bad_line() {
+    let day = find_day(2021, 21).unwrap();
+    let input = "Player 1 starting position: 4\nPlayer 2 startin position: 8\n";
+    let err = (day.solve)(input, Parts::Both).unwrap_err();
+    let err = err
+        .chain()
+        .find_map(|e| e.downcast_ref::<ParseError>())
+        .unwrap();
+    assert_eq!((err.line, err.column), (2, 9));
+}
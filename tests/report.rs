@@ -0,0 +1,45 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use aoc::report::mask;
+
+#[test]
+fn reports_every_day() {
+    let dir = common::temp_dir("report");
+    fs::create_dir(dir.join("input")).unwrap();
+    fs::create_dir(dir.join("answers")).unwrap();
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    for day in [1, 2] {
+        fs::copy(
+            examples.join(format!("day{}/example.txt", day)),
+            dir.join(format!("input/day{}.txt", day)),
+        )
+        .unwrap();
+    }
+    fs::write(dir.join("answers/day1.txt"), "7\n5\n").unwrap();
+    fs::write(dir.join("answers/day2.txt"), "150\n901\n").unwrap();
+
+    let out = common::run_aoc(&dir, &["report", "1", "2", "3", "--html", "report.html"]);
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    let rows: Vec<Vec<&str>> = stdout
+        .lines()
+        .skip(2)
+        .take(3)
+        .map(|l| l.split(" | ").take(4).collect())
+        .collect();
+    assert_eq!(rows[0], ["| 1", "verified", "7", "5"]);
+    assert_eq!(rows[1], ["| 2", "wrong", "150", "900"]);
+    assert_eq!(rows[2][..2], ["| 3", "error"]);
+    assert!(stdout.contains("2 of 3 days solved"), "{}", stdout);
+
+    let html = fs::read_to_string(dir.join("report.html")).unwrap();
+    assert!(html.contains("<td>150</td><td>900</td>"), "{}", html);
+
+    let out = common::run_aoc(&dir, &["report", "1", "--mask"]);
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(stdout.contains(&format!("| {} | {} |", mask("7"), mask("5"))));
+    assert_ne!(mask("7"), mask("5"));
+}
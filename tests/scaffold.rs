@@ -0,0 +1,70 @@
+mod common;
+
+use std::fs;
+
+use aoc::scaffold;
+
+const LIB: &str = "pub mod cli;
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod error;
+
+registry![
+    day1::Day1,
+    day2::Day2,
+    day10::Day10,
+];
+";
+
+#[test]
+fn registers_day_in_order() {
+    let lib = scaffold::register(LIB, 3).unwrap();
+    assert_eq!(
+        lib,
+        "pub mod cli;
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+pub mod error;
+
+registry![
+    day1::Day1,
+    day2::Day2,
+    day3::Day3,
+    day10::Day10,
+];
+"
+    );
+
+    let lib = scaffold::register(LIB, 22).unwrap();
+    assert!(lib.contains("pub mod day2;\npub mod day22;\npub mod error;"));
+    assert!(lib.contains("    day10::Day10,\n    day22::Day22,\n];"));
+
+    assert!(scaffold::register(LIB, 10).is_err());
+}
+
+#[test]
+fn generates_files_without_overwriting() {
+    let root = common::temp_dir("scaffold");
+    fs::create_dir_all(root.join("src/bin")).unwrap();
+    fs::write(root.join("src/lib.rs"), LIB).unwrap();
+
+    scaffold::generate(&root, 7).unwrap();
+    let module = fs::read_to_string(root.join("src/day7.rs")).unwrap();
+    assert!(module.contains("pub struct Day7;"));
+    assert!(module.contains("const DAY: u64 = 7;"));
+    assert!(fs::read_to_string(root.join("src/bin/day7.rs"))
+        .unwrap()
+        .contains("aoc_main::<Day7>()"));
+    assert!(fs::read_to_string(root.join("src/lib.rs"))
+        .unwrap()
+        .contains("    day7::Day7,"));
+    assert!(!root.join("input/2021/day7.txt").exists());
+    assert!(root.join("examples/day7/example.txt").exists());
+
+    let err = scaffold::generate(&root, 7).unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{}", err);
+    assert!(scaffold::generate(&root, 26).is_err());
+}
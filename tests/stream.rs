@@ -0,0 +1,76 @@
+mod common;
+
+use std::fs;
+
+use aoc::cli::Parts;
+use aoc::day1::Day1;
+use aoc::day10::Day10;
+use aoc::stream::solve_lines;
+use aoc::{solve, Params, Solution};
+
+fn answers<S: Solution>(path: &str) -> Vec<String> {
+    let params = Params::new(S::PARAMS, &[]).unwrap();
+    let report = solve_lines::<S>(Some(path), Parts::Both, &params, None).unwrap();
+    [1, 2]
+        .into_iter()
+        .map(|part| report.answer(part).unwrap().answer.clone())
+        .collect()
+}
+
+fn example(day: u64) -> String {
+    let path = format!(
+        "{}/examples/day{}/example.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        day
+    );
+    fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn streamed_inputs_match_whole_ones() {
+    let dir = common::temp_dir("stream");
+    for day in [1, 10] {
+        // Streaming normalises the input the same way as reading it all
+        let messy = format!("\u{feff}{}\n\n", example(day).replace('\n', "\r\n"));
+        let path = dir.join(format!("day{}.txt", day));
+        fs::write(&path, messy).unwrap();
+        let path = path.to_str().unwrap();
+
+        let (streamed, whole) = match day {
+            1 => (
+                answers::<Day1>(path),
+                solve::<Day1>(&example(1), Parts::Both),
+            ),
+            _ => (
+                answers::<Day10>(path),
+                solve::<Day10>(&example(10), Parts::Both),
+            ),
+        };
+        let whole = whole.unwrap();
+        assert_eq!(streamed[0], whole.answer(1).unwrap().answer);
+        assert_eq!(streamed[1], whole.answer(2).unwrap().answer);
+    }
+}
+
+#[test]
+fn streaming_errors() {
+    let dir = common::temp_dir("stream-errors");
+    let path = dir.join("depths.txt");
+    fs::write(&path, "199\n200\n2o8\n207\n").unwrap();
+
+    let out = common::run_aoc(&dir, &["run", "1", "--stream", "depths.txt"]);
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert_eq!(out.status.code(), Some(4), "{}", stderr);
+    assert!(stderr.contains("line 3"), "{}", stderr);
+
+    let out = common::run_aoc(&dir, &["run", "1", "--stream", "missing.txt"]);
+    assert_eq!(out.status.code(), Some(2));
+
+    let out = common::run_aoc(&dir, &["run", "12", "--stream", "depths.txt"]);
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert!(
+        stderr.contains("can't be parsed a line at a time"),
+        "{}",
+        stderr
+    );
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "fetch")]
+
+mod common;
+
+use std::time::Duration;
+
+use aoc::fetch::Client;
+use aoc::submit::{self, Outcome, SubmissionLog, Verdict};
+
+const CORRECT: &str = "<main><article><p>That's the right answer! You are one gold star \
+    closer to finding the sleigh keys.</p></article></main>";
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+    high. Please wait one minute before trying again.</p></article></main>";
+const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low. \
+    Please wait 5 minutes before trying again.</p></article></main>";
+const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait \
+    after submitting an answer before trying again. You have 1m 23s left to wait.</p></article>\
+    </main>";
+const WRONG_LEVEL: &str = "<main><article><p>You don't seem to be solving the right level. \
+    Did you already complete it?</p></article></main>";
+
+#[test]
+fn parses_responses() {
+    let minute = Duration::from_secs(60);
+    assert_eq!(Outcome::parse(CORRECT), Outcome::Correct);
+    assert_eq!(Outcome::parse(TOO_HIGH), Outcome::TooHigh { wait: minute });
+    assert_eq!(
+        Outcome::parse(TOO_LOW),
+        Outcome::TooLow { wait: 5 * minute }
+    );
+    assert_eq!(
+        Outcome::parse(TOO_RECENT),
+        Outcome::RateLimited {
+            wait: Duration::from_secs(83)
+        }
+    );
+    assert_eq!(Outcome::parse(WRONG_LEVEL), Outcome::WrongLevel);
+    assert!(matches!(
+        Outcome::parse("<html></html>"),
+        Outcome::Unknown(_)
+    ));
+}
+
+#[test]
+fn posts_answer_form() {
+    let (base_url, requests) = common::serve(vec![(200, CORRECT)]);
+    let client = Client::new(base_url, "s3cret");
+    assert_eq!(
+        client.submit_answer(2021, 3, 2, "1234").unwrap(),
+        Outcome::Correct
+    );
+
+    let req = requests.recv().unwrap();
+    assert_eq!(req.method, "POST");
+    assert_eq!(req.path, "/2021/day/3/answer");
+    assert_eq!(req.header("cookie"), Some("session=s3cret"));
+    assert_eq!(req.body, "level=2&answer=1234");
+}
+
+#[test]
+fn remembers_wrong_answers_and_cooldowns() {
+    let (base_url, _requests) = common::serve(vec![(200, TOO_HIGH)]);
+    let client = Client::new(base_url, "s3cret");
+    let path = common::temp_dir("submit").join("submissions.txt");
+
+    let mut log = SubmissionLog::load(&path).unwrap();
+    let outcome = submit::submit(&client, &mut log, 2021, 1, 1, "500").unwrap();
+    assert_eq!(
+        outcome,
+        Outcome::TooHigh {
+            wait: Duration::from_secs(60)
+        }
+    );
+    log.save(&path).unwrap();
+
+    let log = SubmissionLog::load(&path).unwrap();
+    assert_eq!(log.submissions.len(), 1);
+    assert_eq!(log.submissions[0].verdict, Verdict::TooHigh);
+    assert_eq!(log.submissions[0].answer, "500");
+
+    // Known-bad values are refused no matter when they're submitted
+    let later = log.cooldown_until + 1;
+    assert!(log.check(2021, 1, 1, "500", later).is_some());
+    assert!(log.check(2021, 1, 1, "600", later).is_some());
+    assert!(log.check(2021, 1, 1, "400", later).is_none());
+    assert!(log.check(2021, 1, 2, "500", later).is_none());
+
+    // Anything else has to wait for the cooldown
+    assert!(log.check(2021, 1, 1, "400", later - 2).is_some());
+}
+
+#[test]
+fn rate_limit_sets_cooldown_without_recording_answer() {
+    let mut log = SubmissionLog::default();
+    log.record(2021, 2, 1, "42", &Outcome::parse(TOO_RECENT), 1000);
+    assert!(log.submissions.is_empty());
+    assert_eq!(log.cooldown_until, 1083);
+    assert!(log.check(2021, 2, 1, "42", 1082).is_some());
+    assert!(log.check(2021, 2, 1, "42", 1083).is_none());
+}
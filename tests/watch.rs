@@ -0,0 +1,24 @@
+mod common;
+
+use std::fs;
+
+use aoc::watch::Watcher;
+
+#[test]
+fn notices_changed_created_and_deleted_files() {
+    let dir = common::temp_dir("watch");
+    let existing = dir.join("day1.txt");
+    let missing = dir.join("day2.txt");
+    fs::write(&existing, "1\n2\n").unwrap();
+
+    let mut watcher = Watcher::new(vec![existing.clone(), missing.clone()]);
+    assert!(watcher.poll().is_empty());
+
+    fs::write(&existing, "1\n2\n3\n").unwrap();
+    assert_eq!(watcher.poll(), [&existing]);
+    assert!(watcher.poll().is_empty());
+
+    fs::write(&missing, "4\n").unwrap();
+    fs::remove_file(&existing).unwrap();
+    assert_eq!(watcher.poll(), [&existing, &missing]);
+}
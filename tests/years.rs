@@ -0,0 +1,49 @@
+mod common;
+
+use std::fs;
+
+use aoc::{day_label, parse_day, parse_day_label, DEFAULT_YEAR};
+
+#[test]
+fn day_labels() {
+    assert_eq!(parse_day_label("5").unwrap(), (DEFAULT_YEAR, 5));
+    assert_eq!(parse_day_label("2022/5").unwrap(), (2022, 5));
+    assert!(parse_day_label("2022/").is_err());
+    assert_eq!(day_label(DEFAULT_YEAR, 5), "5");
+    assert_eq!(day_label(2022, 5), "2022/5");
+
+    assert_eq!(parse_day("2021/6").unwrap().day, 6);
+    let err = parse_day("2015/6").err().unwrap();
+    assert_eq!(err.to_string(), "no solution for day 6 of 2015");
+}
+
+#[test]
+fn inputs_are_found_in_either_layout() {
+    let dir = common::temp_dir("years");
+    let example = |day: u64| {
+        let path = format!(
+            "{}/examples/day{}/example.txt",
+            env!("CARGO_MANIFEST_DIR"),
+            day
+        );
+        fs::read_to_string(path).unwrap()
+    };
+    fs::create_dir_all(dir.join("input/2021")).unwrap();
+    fs::write(dir.join("input/day1.txt"), example(1)).unwrap();
+    fs::write(dir.join("input/2021/day2.txt"), example(2)).unwrap();
+
+    for (day, answer) in [("1", "Part 1 Count: 7 "), ("2021/2", "Part 1 Count: 150 ")] {
+        let out = common::run_aoc(&dir, &["run", day, "--part", "1"]);
+        let stdout = String::from_utf8(out.stdout).unwrap();
+        assert!(
+            out.status.success(),
+            "{}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        assert!(stdout.contains(answer), "{}", stdout);
+    }
+
+    let out = common::run_aoc(&dir, &["run", "3"]);
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert!(stderr.contains("input/2021/day3.txt"), "{}", stderr);
+}